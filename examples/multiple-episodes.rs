@@ -12,7 +12,7 @@ pub fn main() {
     if let Some(ext) = m.extension() {
         println!("Extension: {}", ext);
     } else {
-        println!("");
+        println!();
     }
 
     let m = Metadata::from(
@@ -32,6 +32,6 @@ pub fn main() {
     if let Some(ext) = m.extension() {
         println!("Extension: {}", ext);
     } else {
-        println!("");
+        println!();
     }
 }
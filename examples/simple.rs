@@ -40,5 +40,5 @@ pub fn main() {
     for i in m4.episodes().iter() {
         print!("Episode {}, ", i);
     }
-    println!("");
+    println!();
 }
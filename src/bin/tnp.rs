@@ -0,0 +1,333 @@
+use std::io::{self, BufRead, Write};
+use std::process;
+use torrent_name_parser::Metadata;
+
+const USAGE: &str = "\
+Usage: tnp [--format <table|json|csv|tsv>] [NAME]...
+
+Parses each NAME, or each line of stdin when no NAME is given, and prints the
+resulting metadata.
+
+Options:
+  -f, --format <FORMAT>  Output format [default: table]
+  -h, --help             Print this help
+
+Exits with status 1 when at least one name could not be parsed.";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+    Tsv,
+}
+
+enum Value {
+    Text(String),
+    Number(i32),
+    List(Vec<i32>),
+}
+
+impl Value {
+    fn plain(&self) -> String {
+        match self {
+            Value::Text(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::List(l) => l.iter().map(i32::to_string).collect::<Vec<_>>().join(","),
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            Value::Text(s) => json_string(s),
+            Value::Number(n) => n.to_string(),
+            Value::List(l) => format!(
+                "[{}]",
+                l.iter().map(i32::to_string).collect::<Vec<_>>().join(",")
+            ),
+        }
+    }
+}
+
+const COLUMNS: &[&str] = &[
+    "name",
+    "title",
+    "year",
+    "air_date",
+    "season",
+    "seasons",
+    "episodes",
+    "absolute_episode",
+    "episode_title",
+    "part",
+    "total_parts",
+    "disc",
+    "resolution",
+    "quality",
+    "codec",
+    "audio",
+    "dynamic_range",
+    "bit_depth",
+    "streaming_service",
+    "edition",
+    "flags",
+    "group",
+    "country",
+    "language",
+    "languages",
+    "subtitles",
+    "extension",
+    "imdb_tag",
+    "checksum",
+    "region",
+    "container",
+    "size_bytes",
+    "website",
+];
+
+fn row(name: &str, m: &Metadata) -> Vec<Option<Value>> {
+    let text = |s: Option<String>| s.map(Value::Text);
+    vec![
+        Some(Value::Text(name.to_string())),
+        Some(Value::Text(m.title().to_string())),
+        m.year().map(Value::Number),
+        text(m.air_date().map(|d| d.to_string())),
+        m.season().map(Value::Number),
+        Some(m.seasons().clone())
+            .filter(|s| s.len() > 1)
+            .map(Value::List),
+        Some(m.episodes().clone())
+            .filter(|e| !e.is_empty())
+            .map(Value::List),
+        m.absolute_episode().map(Value::Number),
+        text(m.episode_title().map(String::from)),
+        m.part().map(Value::Number),
+        m.total_parts().map(Value::Number),
+        m.disc().map(Value::Number),
+        text(m.resolution().map(ToString::to_string)),
+        text(m.quality().map(ToString::to_string)),
+        text(m.codec().map(ToString::to_string)),
+        text(m.audio().map(ToString::to_string)),
+        Some(m.dynamic_range()).filter(|r| !r.is_empty()).map(|r| {
+            Value::Text(
+                r.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        }),
+        m.bit_depth().map(|d| Value::Number(d.into())),
+        text(m.streaming_service().map(|s| s.to_string())),
+        text(m.edition().map(|e| e.to_string())),
+        Some(m.flags())
+            .filter(|f| !f.is_empty())
+            .map(|f| Value::Text(f.to_string())),
+        text(m.group().map(String::from)),
+        text(m.country().map(String::from)),
+        text(m.language().map(String::from)),
+        Some(m.languages()).filter(|l| !l.is_empty()).map(|l| {
+            Value::Text(
+                l.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        }),
+        Some(m.subtitles()).filter(|s| !s.is_empty()).map(|s| {
+            Value::Text(
+                s.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        }),
+        text(m.extension().map(String::from)),
+        text(m.imdb_tag().map(String::from)),
+        text(m.checksum().map(|crc| format!("{:08X}", crc))),
+        text(m.region().map(String::from)),
+        text(m.container().map(String::from)),
+        text(m.size_bytes().map(|s| s.to_string())),
+        text(m.website().map(String::from)),
+    ]
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn tsv_field(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+fn print_header(out: &mut impl Write, format: Format) -> io::Result<()> {
+    match format {
+        Format::Csv => writeln!(out, "{}", COLUMNS.join(",")),
+        Format::Tsv => writeln!(out, "{}", COLUMNS.join("\t")),
+        Format::Table | Format::Json => Ok(()),
+    }
+}
+
+fn print_row(out: &mut impl Write, format: Format, row: &[Option<Value>]) -> io::Result<()> {
+    let cells = COLUMNS.iter().zip(row);
+    match format {
+        Format::Table => {
+            let width = COLUMNS.iter().map(|c| c.len()).max().unwrap_or(0);
+            for (column, value) in cells {
+                if let Some(value) = value {
+                    writeln!(out, "{:<width$} {}", column, value.plain(), width = width)?;
+                }
+            }
+            writeln!(out)
+        }
+        Format::Json => {
+            let fields: Vec<String> = cells
+                .filter_map(|(column, value)| {
+                    value
+                        .as_ref()
+                        .map(|v| format!("{}:{}", json_string(column), v.json()))
+                })
+                .collect();
+            writeln!(out, "{{{}}}", fields.join(","))
+        }
+        Format::Csv | Format::Tsv => {
+            let fields: Vec<String> = cells
+                .map(|(_, value)| {
+                    let plain = value.as_ref().map(Value::plain).unwrap_or_default();
+                    if format == Format::Csv {
+                        csv_field(&plain)
+                    } else {
+                        tsv_field(&plain)
+                    }
+                })
+                .collect();
+            let separator = if format == Format::Csv { "," } else { "\t" };
+            writeln!(out, "{}", fields.join(separator))
+        }
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("tnp: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let mut format = Format::Table;
+    let mut names = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "-f" | "--format" => {
+                format = match args.next().as_deref() {
+                    Some("table") => Format::Table,
+                    Some("json") => Format::Json,
+                    Some("csv") => Format::Csv,
+                    Some("tsv") => Format::Tsv,
+                    Some(other) => usage_error(&format!("unknown format `{}`", other)),
+                    None => usage_error("missing value for --format"),
+                }
+            }
+            "--" => names.extend(args.by_ref()),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                usage_error(&format!("unknown option `{}`", arg))
+            }
+            _ => names.push(arg),
+        }
+    }
+    if names.is_empty() {
+        names = io::stdin()
+            .lock()
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut failed = false;
+    let result = print_header(&mut out, format).and_then(|_| {
+        for name in &names {
+            match Metadata::from(name) {
+                Ok(m) => print_row(&mut out, format, &row(name, &m))?,
+                Err(e) => {
+                    failed = true;
+                    eprintln!("tnp: {}: {}", name, e);
+                }
+            }
+        }
+        out.flush()
+    });
+    if let Err(e) = result {
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            eprintln!("tnp: {}", e);
+            process::exit(2);
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json_escaping() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string("a \"b\" \\ c"), r#""a \"b\" \\ c""#);
+        assert_eq!(json_string("tab\tnew\nline\r"), r#""tab\tnew\nline\r""#);
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+    }
+
+    #[test]
+    fn csv_and_tsv_escaping() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), r#""a,b""#);
+        assert_eq!(csv_field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(tsv_field("a\tb\nc"), "a b c");
+    }
+
+    #[test]
+    fn table_is_aligned() {
+        let m = Metadata::from("Show.S01E02.1080p.AMZN.WEB-DL-GRP").unwrap();
+        let mut out = Vec::new();
+        print_row(&mut out, Format::Table, &row("name", &m)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("streaming_service"));
+        let width = "streaming_service".len();
+        for line in out.lines().filter(|line| !line.is_empty()) {
+            let (column, value) = line.split_at(width + 1);
+            assert!(COLUMNS.contains(&column.trim_end()), "{}", line);
+            assert!(!value.starts_with(' '), "{}", line);
+        }
+    }
+}
@@ -1,25 +1,55 @@
+use crate::metadata::Metadata;
 use std::error::Error;
 use std::fmt;
 
-#[derive(Debug)]
-pub struct ErrorMatch {
-    matches: Vec<(&'static str, Option<String>)>,
+/// Failure to parse a torrent name. Every variant carries the partially
+/// filled `Metadata` so callers can still use whatever was recognized.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Every part of the name was consumed by other fields.
+    MissingTitle(Box<Metadata>),
 }
 
-impl ErrorMatch {
-    pub fn new(matches: Vec<(&'static str, Option<String>)>) -> ErrorMatch {
-        ErrorMatch { matches }
+impl ParseError {
+    pub fn metadata(&self) -> &Metadata {
+        match self {
+            ParseError::MissingTitle(m) => m,
+        }
+    }
+    pub fn into_metadata(self) -> Metadata {
+        match self {
+            ParseError::MissingTitle(m) => *m,
+        }
     }
 }
 
-impl fmt::Display for ErrorMatch {
+impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.matches)
+        match self {
+            ParseError::MissingTitle(_) => write!(f, "Couldn't find a title."),
+        }
     }
 }
 
-impl Error for ErrorMatch {
-    fn description(&self) -> &str {
-        "Couldn't find a title."
-    }
+impl Error for ParseError {}
+
+#[deprecated(note = "use `ParseError` instead")]
+pub type ErrorMatch = ParseError;
+
+/// Recoverable problems noticed while parsing, reported by
+/// `Metadata::warnings()`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum ParseWarning {
+    /// Every part of the name was consumed by other fields, the title is empty.
+    MissingTitle,
+    /// The air date could be read either day or month first, as in
+    /// `03.04.2019`. The day was assumed to come first.
+    AmbiguousDate,
 }
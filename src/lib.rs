@@ -1,11 +1,22 @@
 #[macro_use]
 extern crate lazy_static;
-extern crate regex;
+pub extern crate regex;
 
+mod checksum;
 pub mod error;
 mod metadata;
+mod parser;
 mod pattern;
 #[cfg(test)]
 mod test;
+mod types;
 
+pub use checksum::crc32;
+pub use error::{ParseError, ParseWarning};
 pub use metadata::Metadata;
+pub use parser::{Field, LeadingBracket, Parser, ParserBuilder};
+pub use pattern::Pattern;
+pub use types::{
+    Audio, AudioFormat, Date, DynamicRange, Edition, Language, Quality, ReleaseFlags, Resolution,
+    Source, Span, StreamingService, Subtitle, VideoCodec,
+};
@@ -1,76 +1,425 @@
-use crate::error::ErrorMatch;
+use crate::checksum;
+use crate::error::{ParseError, ParseWarning};
+use crate::parser::{Field, LeadingBracket, Parser};
 use crate::pattern;
 use crate::pattern::Pattern;
+use crate::types::{
+    Audio, Date, DynamicRange, Edition, Language, Quality, ReleaseFlags, Resolution, Source, Span,
+    StreamingService, Subtitle, VideoCodec,
+};
 use regex::Captures;
 use std::borrow::Cow;
 use std::cmp::{max, min};
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+use std::path::Path;
 
 use std::{convert::TryFrom, str::FromStr};
 
+/// Metadata parsed from a torrent name.
+///
+/// With the `serde` feature enabled, `Metadata` serializes to a map whose
+/// keys are the names of its accessors (`title`, `season`, `quality`,
+/// `quality_raw`, `imdb_tag`, ...). Values that may be unknown, such as
+/// `Quality`, are written as their `Display` string, while closed enums such
+/// as `DynamicRange` are written as their snake_case variant name
+/// (`dolby_vision`) and `Language` as its ISO 639 code (`en`). Missing keys
+/// deserialize to their default value.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Metadata {
     title: String,
     season: Option<i32>,
+    seasons: Vec<i32>,
+    complete_series: bool,
     episode: Option<i32>,
     episodes: Vec<i32>,
+    episode_title: Option<String>,
+    absolute_episode: Option<i32>,
+    release_version: Option<u8>,
+    part: Option<i32>,
+    total_parts: Option<i32>,
+    disc: Option<i32>,
     year: Option<i32>,
-    resolution: Option<String>,
-    quality: Option<String>,
-    codec: Option<String>,
-    audio: Option<String>,
+    air_date: Option<Date>,
+    resolution: Option<Resolution>,
+    resolution_raw: Option<String>,
+    quality: Option<Quality>,
+    quality_raw: Option<String>,
+    codec: Option<VideoCodec>,
+    codec_raw: Option<String>,
+    audio: Option<Audio>,
+    audio_raw: Option<String>,
+    dynamic_range: Vec<DynamicRange>,
+    bit_depth: Option<u8>,
+    streaming_service: Option<StreamingService>,
     group: Option<String>,
     country: Option<String>,
+    edition: Option<Edition>,
     extended: bool,
     hardcoded: bool,
-    proper: bool,
-    repack: bool,
+    flags: ReleaseFlags,
     widescreen: bool,
     unrated: bool,
     three_d: bool,
+    #[cfg_attr(feature = "serde", serde(rename = "imdb_tag"))]
     imdb: Option<String>,
+    checksum: Option<u32>,
     extension: Option<String>,
     language: Option<String>,
+    languages: Vec<Language>,
+    subtitles: Vec<Subtitle>,
+    region: Option<String>,
+    container: Option<String>,
+    #[cfg_attr(feature = "serde", serde(rename = "size_bytes"))]
+    size: Option<u64>,
+    website: Option<String>,
+    extra: BTreeMap<String, String>,
+    spans: BTreeMap<String, Span>,
+    sources: BTreeMap<String, Source>,
+    warnings: Vec<ParseWarning>,
 }
 
-fn check_pattern_and_extract<'a>(
-    pattern: &Pattern,
-    torrent_name: &'a str,
-    title_start: &mut usize,
-    title_end: &mut usize,
-    extract_value: impl Fn(Captures<'a>) -> Option<&'a str>,
-) -> Option<&'a str> {
-    pattern.captures(torrent_name).and_then(|caps| {
+/// Tracks the region of the name that is still considered part of the title
+/// along with the span of every field matched so far.
+struct Bounds<'p> {
+    parser: &'p Parser,
+    title_start: usize,
+    title_end: usize,
+    /// Regions such as the leading bracket or the checksum where matches of
+    /// other fields are ignored.
+    ignored: Vec<Span>,
+    spans: BTreeMap<String, Span>,
+}
+
+impl<'p> Bounds<'p> {
+    fn new(parser: &'p Parser, name: &str) -> Self {
+        Bounds {
+            parser,
+            title_start: 0,
+            title_end: name.len(),
+            ignored: Vec::new(),
+            spans: BTreeMap::new(),
+        }
+    }
+
+    fn keeps(&self, caps: &Captures<'_>) -> bool {
+        let start = caps.get(0).unwrap().start();
+        !self
+            .ignored
+            .iter()
+            .any(|span| span.start <= start && start < span.end)
+    }
+
+    /// Where the year, season or episode marker starts, or the title ends when
+    /// there is none yet.
+    fn marker_start(&self) -> usize {
+        [
+            Field::Year,
+            Field::AirDate,
+            Field::Season,
+            Field::Episode,
+            Field::AbsoluteEpisode,
+        ]
+        .iter()
+        .filter_map(|field| self.spans.get(field.as_str()))
+        .map(|span| span.start)
+        .min()
+        .unwrap_or(self.title_end)
+    }
+
+    fn update(&mut self, key: &str, pattern: &Pattern, caps: &Captures<'_>) {
         if let Some(cap) = caps.get(0) {
             if pattern.before_title() {
-                *title_start = max(*title_start, cap.end());
+                self.title_start = max(self.title_start, cap.end());
             } else {
-                *title_end = min(*title_end, cap.start());
+                self.title_end = min(self.title_end, cap.start());
             }
+            self.spans
+                .insert(key.to_string(), Span::new(cap.start(), cap.end()));
         }
-        extract_value(caps)
-    })
+    }
+}
+
+fn check_pattern_and_extract<'a>(
+    field: Field,
+    pattern: &Pattern,
+    torrent_name: &'a str,
+    bounds: &mut Bounds,
+    extract_value: impl Fn(Captures<'a>) -> Option<&'a str>,
+) -> Option<&'a str> {
+    check_pattern(field, pattern, torrent_name, bounds).and_then(extract_value)
+}
+
+/// Reads the language of a sidecar file, given as `en`, `eng`, `English` or
+/// with a region as in `pt-BR`.
+fn sidecar_language(tag: &str) -> Option<Language> {
+    let code = match tag.split_once(['-', '_']) {
+        Some((code, region)) if code.len() <= 3 && region.len() <= 4 => code,
+        _ => tag,
+    };
+    Language::from_raw(code)
+}
+
+fn subtitle_tag(caps: &Captures<'_>) -> Subtitle {
+    let language = if caps.name("english").is_some() {
+        Some(Language::English)
+    } else if caps.name("multi").is_some() {
+        Some(Language::Multi)
+    } else {
+        ["lang", "vost", "sub"]
+            .iter()
+            .find_map(|&group| caps.name(group))
+            .and_then(|m| Language::from_raw(m.as_str()))
+    };
+    Subtitle {
+        language,
+        ..Subtitle::default()
+    }
+}
+
+/// Like `check_pattern` but returns every match, the span of the field
+/// covering all of them.
+fn check_pattern_all<'a>(
+    field: Field,
+    pattern: &Pattern,
+    torrent_name: &'a str,
+    bounds: &mut Bounds,
+) -> Vec<Captures<'a>> {
+    check_pattern_all_where(field, pattern, torrent_name, bounds, |_| true)
+}
+
+/// Like `check_pattern_all` but only considers the matches accepted by `keep`.
+fn check_pattern_all_where<'a>(
+    field: Field,
+    pattern: &Pattern,
+    torrent_name: &'a str,
+    bounds: &mut Bounds,
+    keep: impl Fn(&Captures<'a>) -> bool,
+) -> Vec<Captures<'a>> {
+    if !bounds.parser.is_enabled(field) {
+        return Vec::new();
+    }
+    let all: Vec<_> = pattern
+        .captures_iter(torrent_name)
+        .filter(|caps| bounds.keeps(caps) && keep(caps))
+        .collect();
+    for caps in &all {
+        bounds.update(field.as_str(), pattern, caps);
+    }
+    if let (Some(first), Some(last)) = (all.first(), all.last()) {
+        let span = Span::new(first.get(0).unwrap().start(), last.get(0).unwrap().end());
+        bounds.spans.insert(field.to_string(), span);
+    }
+    all
 }
 
 fn check_pattern<'a>(
+    field: Field,
     pattern: &Pattern,
     torrent_name: &'a str,
-    title_start: &mut usize,
-    title_end: &mut usize,
+    bounds: &mut Bounds,
 ) -> Option<Captures<'a>> {
-    pattern.captures(torrent_name).map(|caps| {
-        if let Some(cap) = caps.get(0) {
-            if pattern.before_title() {
-                *title_start = max(*title_start, cap.end());
-            } else {
-                *title_end = min(*title_end, cap.start());
+    check_pattern_where(field, pattern, torrent_name, bounds, |_| true)
+}
+
+/// Like `check_pattern` but only considers the matches accepted by `keep`.
+fn check_pattern_where<'a>(
+    field: Field,
+    pattern: &Pattern,
+    torrent_name: &'a str,
+    bounds: &mut Bounds,
+    keep: impl Fn(&Captures<'a>) -> bool,
+) -> Option<Captures<'a>> {
+    if !bounds.parser.is_enabled(field) {
+        return None;
+    }
+    pattern
+        .captures_where(torrent_name, |caps| bounds.keeps(caps) && keep(caps))
+        .inspect(|caps| bounds.update(field.as_str(), pattern, caps))
+}
+
+/// The edition part of a `pattern::EDITION` match, which for `DC` excludes
+/// the year before it.
+fn edition_match<'t>(caps: &Captures<'t>) -> Option<regex::Match<'t>> {
+    caps.name("edition").or_else(|| caps.name("dc"))
+}
+
+const ROMAN_NUMERALS: &[&str] = &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"];
+
+/// Reads a part number written either in digits or as a roman numeral from
+/// `I` to `X`.
+fn part_number(raw: &str) -> Option<i32> {
+    if let Ok(number) = raw.parse() {
+        return Some(number);
+    }
+    ROMAN_NUMERALS
+        .iter()
+        .position(|&numeral| numeral == raw)
+        .map(|i| i as i32 + 1)
+}
+
+/// Converts a `pattern::SIZE` match to bytes. `KB`, `MB`... are decimal units,
+/// `KiB`, `MiB`... binary ones.
+fn size_bytes(caps: &Captures<'_>) -> Option<u64> {
+    let value: f64 = caps
+        .name("value")?
+        .as_str()
+        .replace(',', ".")
+        .parse()
+        .ok()?;
+    let unit = caps.name("unit")?.as_str().to_ascii_uppercase();
+    let base: f64 = if unit.contains('I') { 1024.0 } else { 1000.0 };
+    let exponent = match unit.as_bytes()[0] {
+        b'K' => 1,
+        b'M' => 2,
+        b'G' => 3,
+        _ => 4,
+    };
+    Some((value * base.powi(exponent)).round() as u64)
+}
+
+/// How to read the leading bracket of `name`, and whether the name follows
+/// fansub naming: a bracketed group that is not a website followed by an
+/// episode number after ` - `.
+fn leading_bracket(parser: &Parser, name: &str) -> (LeadingBracket, bool) {
+    let anime = pattern::WEBSITE.captures(name).is_some_and(|caps| {
+        !caps[2].contains('.')
+            && pattern::ABSOLUTE_EPISODE
+                .captures_where(name, |next| {
+                    next.get(0).unwrap().start() >= caps[0].len() && !is_year_episode(next)
+                })
+                .is_some()
+    });
+    match anime {
+        true => (LeadingBracket::Group, true),
+        false => (parser.leading_bracket(), false),
+    }
+}
+
+/// Whether a `pattern::ABSOLUTE_EPISODE` match is a lone year, as in
+/// `[Group] Show - 2019 [1080p]`.
+fn is_year_episode(caps: &Captures<'_>) -> bool {
+    caps.name("version").is_none()
+        && caps.name("last").is_none()
+        && caps
+            .name("first")
+            .is_some_and(|m| pattern::YEAR.captures(m.as_str()).is_some())
+}
+
+/// Lists the seasons of a `pattern::SEASON_RANGE` match. Of a reversed range
+/// such as `S05-S01` only the first season is kept.
+fn season_range(caps: &Captures<'_>) -> Vec<i32> {
+    let number = |group| caps.name(group)?.as_str().parse::<i32>().ok();
+    if let Some(list) = caps.name("list") {
+        return list
+            .as_str()
+            .split([' ', '.'])
+            .filter_map(|s| s[1..].parse().ok())
+            .collect();
+    }
+    let first = number("first").or_else(|| number("long_first"));
+    let last = number("last").or_else(|| number("long_last"));
+    match (first, last) {
+        (Some(first), Some(last)) if last < first => vec![first],
+        (Some(first), Some(last)) => (first..=last).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether `raw` is a date written `YYYYMMDD` rather than a CRC32, as in
+/// `(20190314)`.
+fn is_compact_date(raw: &str) -> bool {
+    if raw.len() != 8 || !raw.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let (year, month, day) = (&raw[..4], &raw[4..6], &raw[6..]);
+    Date::new(
+        year.parse().unwrap(),
+        month.parse().unwrap(),
+        day.parse().unwrap(),
+    )
+    .is_some()
+}
+
+/// Reads a `pattern::AIR_DATE` match. Dates written as `03.04.2019` are
+/// taken day first and reported with `ParseWarning::AmbiguousDate`.
+fn air_date(caps: &Captures<'_>, warnings: &mut Vec<ParseWarning>) -> Option<Date> {
+    let number = |group| caps.name(group)?.as_str().parse::<u16>().ok();
+    if let Some(year) = number("year") {
+        return Date::new(year as i32, number("month")? as u8, number("day")? as u8);
+    }
+    let year = number("trailing_year")? as i32;
+    let (first, second) = (number("first")? as u8, number("second")? as u8);
+    match (
+        Date::new(year, second, first),
+        Date::new(year, first, second),
+    ) {
+        (Some(day_first), Some(month_first)) => {
+            if day_first != month_first {
+                warnings.push(ParseWarning::AmbiguousDate);
             }
+            Some(day_first)
         }
-        caps
-    })
+        (day_first, month_first) => day_first.or(month_first),
+    }
+}
+
+/// Whether a `pattern::AIR_DATE` match is rather an audio channel layout, as
+/// the `5.1` of `Movie.2012.5.1.DTS` or `Movie AAC 5.1 2019`.
+fn is_channel_layout(caps: &Captures<'_>, audio: &[Span]) -> bool {
+    let date = caps.get(0).unwrap();
+    if audio
+        .iter()
+        .any(|span| span.start < date.end() && date.start() < span.end)
+    {
+        return true;
+    }
+    let pair = match caps.name("year") {
+        Some(_) => (caps.name("month"), caps.name("day")),
+        None => (caps.name("first"), caps.name("second")),
+    };
+    match pair {
+        (Some(speakers), Some(lfe)) => {
+            speakers.as_str().len() == 1 && lfe.as_str().len() == 1 && lfe.as_str() <= "2"
+        }
+        _ => false,
+    }
 }
 
-fn capture_to_string(caps: Option<Captures<'_>>) -> Option<String> {
-    caps.and_then(|c| c.get(0)).map(|m| m.as_str().to_string())
+/// The text between the episode marker, or the air date of daily shows, and
+/// the next matched field, as in `Euphoria.US.S01E03.Made.You.Look.1080p`.
+fn episode_title(name: &str, spans: &BTreeMap<String, Span>) -> Option<(String, Span)> {
+    let episode = spans
+        .get(Field::Episode.as_str())
+        .or_else(|| spans.get(Field::AirDate.as_str()))?;
+    let start = spans
+        .get(Field::Season.as_str())
+        .map_or(episode.end, |season| max(season.end, episode.end));
+    let end = spans
+        .values()
+        .map(|span| span.start)
+        .filter(|&pos| pos >= start)
+        .chain(name[start..].find(['(', '[']).map(|pos| start + pos))
+        .min()
+        .unwrap_or(name.len());
+    let separators = |c| " -._".contains(c);
+    let region = &name[start..end];
+    let raw = region.trim_start_matches(separators);
+    let offset = start + region.len() - raw.len();
+    let raw = raw.trim_end_matches(separators);
+    if raw.is_empty() {
+        return None;
+    }
+    let title = match !raw.contains(' ') && raw.contains('.') {
+        true => raw.replace(['.', '_'], " "),
+        false => raw.replace('_', " "),
+    };
+    Some((title, Span::new(offset, offset + raw.len())))
 }
 
 impl Metadata {
@@ -84,19 +433,102 @@ impl Metadata {
     ///   assert_eq!(m.is_show(), true);
     ///   // Season is not 0 (zero) meaning it is not a Season Special. Eg: Christmas Special
     ///   assert_eq!(m.is_special(), false);
-
     /// }
     ///```
-    pub fn from(name: &str) -> Result<Self, ErrorMatch> {
+    pub fn from(name: &str) -> Result<Self, ParseError> {
         Metadata::from_str(name)
     }
 
+    /// Like `Metadata::from`, but never fails: when no title can be found the
+    /// title is left empty and `ParseWarning::MissingTitle` is reported in
+    /// `warnings()`.
+    ///```
+    /// use torrent_name_parser::{Metadata, ParseWarning};
+    ///
+    /// let m = Metadata::from_lenient("S01E01.720p.HDTV");
+    /// assert_eq!(m.title(), "");
+    /// assert_eq!(m.episode(), Some(1));
+    /// assert_eq!(m.warnings(), &[ParseWarning::MissingTitle]);
+    ///```
+    pub fn from_lenient(name: &str) -> Self {
+        Parser::default().parse_lenient(name)
+    }
+
+    /// Parses the name of a sidecar subtitle file such as
+    /// `Movie.2019.en.forced.srt`, see `Parser::parse_subtitle`. Names without
+    /// a subtitle extension are parsed like `Metadata::from`.
+    ///```
+    /// use torrent_name_parser::{Language, Metadata};
+    ///
+    /// let m = Metadata::from_subtitle_file("Movie.2019.eng.sdh.srt").unwrap();
+    /// assert_eq!(m.title(), "Movie");
+    /// assert_eq!(m.extension(), Some("srt"));
+    /// let subtitle = m.subtitles()[0];
+    /// assert_eq!(subtitle.language, Some(Language::English));
+    /// assert!(subtitle.sdh);
+    /// assert!(!subtitle.forced);
+    ///```
+    pub fn from_subtitle_file(name: &str) -> Result<Self, ParseError> {
+        Parser::default().parse_subtitle(name)
+    }
+
+    /// Parses a file path, filling the fields missing from the file name
+    /// with those of its season folder and then of its show folder. When the
+    /// season comes from a folder, a leading number in the file name is the
+    /// episode. See `source()` for where each field was found.
+    ///```
+    /// use std::path::Path;
+    /// use torrent_name_parser::{Metadata, Source};
+    ///
+    /// let path = Path::new("/tv/Breaking Bad/Season 02/05 - Breakage.mkv");
+    /// let m = Metadata::from_path(path).unwrap();
+    /// assert_eq!(m.title(), "Breaking Bad");
+    /// assert_eq!(m.season(), Some(2));
+    /// assert_eq!(m.episode(), Some(5));
+    /// assert_eq!(m.episode_title(), Some("Breakage"));
+    /// assert_eq!(m.source("season"), Some(Source::SeasonFolder));
+    /// assert_eq!(m.source("episode"), Some(Source::FileName));
+    ///```
+    pub fn from_path(path: &Path) -> Result<Self, ParseError> {
+        Parser::default().parse_path(path)
+    }
+
+    /// Parses each file of a multi-file torrent like `Metadata::from_path`,
+    /// filling the fields still missing with those of the torrent name.
+    /// Samples, NFOs and extras such as trailers are skipped. Files without a
+    /// title are kept and report `ParseWarning::MissingTitle`.
+    ///```
+    /// use torrent_name_parser::{Metadata, Source};
+    ///
+    /// let files = [
+    ///     "E01.mkv",
+    ///     "E02.mkv",
+    ///     "Sample/e01-sample.mkv",
+    ///     "Show.S02.1080p.WEB-DL-GROUP.nfo",
+    /// ];
+    /// let parsed = Metadata::from_torrent("Show.S02.1080p.WEB-DL-GROUP", &files);
+    /// assert_eq!(parsed.len(), 2);
+    /// let (file, m) = &parsed[1];
+    /// assert_eq!(file.to_str(), Some("E02.mkv"));
+    /// assert_eq!(m.title(), "Show");
+    /// assert_eq!((m.season(), m.episode()), (Some(2), Some(2)));
+    /// assert_eq!(m.group(), Some("GROUP"));
+    /// assert_eq!(m.source("group"), Some(Source::Torrent));
+    ///```
+    pub fn from_torrent<'f, P: AsRef<Path>>(name: &str, files: &'f [P]) -> Vec<(&'f Path, Self)> {
+        Parser::default().parse_torrent(name, files)
+    }
+
     pub fn title(&self) -> &str {
         &self.title
     }
     pub fn season(&self) -> Option<i32> {
         self.season
     }
+    /// Every season of a pack, e.g. `[1, 2, 3, 4, 5]` for `S01-S05`.
+    pub fn seasons(&self) -> &Vec<i32> {
+        &self.seasons
+    }
     pub fn episode(&self) -> Option<i32> {
         self.episode
     }
@@ -109,20 +541,83 @@ impl Metadata {
     pub fn episodes(&self) -> &Vec<i32> {
         &self.episodes
     }
+    /// Episode number counted from the start of the series, as used by
+    /// fansub releases like `[SubsPlease] Jujutsu Kaisen - 24 (1080p)`.
+    /// `episode()` holds the same number.
+    pub fn absolute_episode(&self) -> Option<i32> {
+        self.absolute_episode
+    }
+    /// Revision of a fansub release, e.g. 2 for `24v2`.
+    pub fn release_version(&self) -> Option<u8> {
+        self.release_version
+    }
+    /// Part of a movie split over several files, e.g. 1 for `Part.1of6` or 2
+    /// for `Pt.II`. Markers before the year are read too but left in the
+    /// title, as in `Kill.Bill.Vol.1.2003`.
+    pub fn part(&self) -> Option<i32> {
+        self.part
+    }
+    /// Number of parts, e.g. 6 for `Part.1of6`.
+    pub fn total_parts(&self) -> Option<i32> {
+        self.total_parts
+    }
+    /// Disc number, e.g. 1 for `CD1` or 2 for `Disc.2`.
+    pub fn disc(&self) -> Option<i32> {
+        self.disc
+    }
+    /// Name of the episode when it follows the episode marker, e.g.
+    /// `Made You Look` in `Euphoria.US.S01E03.Made.You.Look.1080p`.
+    pub fn episode_title(&self) -> Option<&str> {
+        self.episode_title.as_deref()
+    }
     pub fn year(&self) -> Option<i32> {
         self.year
     }
-    pub fn resolution(&self) -> Option<&str> {
-        self.resolution.as_deref()
+    /// Air date of daily shows, e.g. `2019.03.14`.
+    pub fn air_date(&self) -> Option<Date> {
+        self.air_date
+    }
+    pub fn resolution(&self) -> Option<&Resolution> {
+        self.resolution.as_ref()
+    }
+    /// The resolution exactly as it appears in the name, e.g. `2160P`.
+    pub fn resolution_raw(&self) -> Option<&str> {
+        self.resolution_raw.as_deref()
+    }
+    pub fn quality(&self) -> Option<&Quality> {
+        self.quality.as_ref()
+    }
+    /// The quality exactly as it appears in the name, e.g. `HdRip`.
+    pub fn quality_raw(&self) -> Option<&str> {
+        self.quality_raw.as_deref()
+    }
+    pub fn codec(&self) -> Option<&VideoCodec> {
+        self.codec.as_ref()
     }
-    pub fn quality(&self) -> Option<&str> {
-        self.quality.as_deref()
+    /// The codec exactly as it appears in the name, e.g. `h.264/`.
+    pub fn codec_raw(&self) -> Option<&str> {
+        self.codec_raw.as_deref()
     }
-    pub fn codec(&self) -> Option<&str> {
-        self.codec.as_deref()
+    /// Audio format, channel layout and Atmos flag.
+    pub fn audio(&self) -> Option<&Audio> {
+        self.audio.as_ref()
     }
-    pub fn audio(&self) -> Option<&str> {
-        self.audio.as_deref()
+    /// The audio exactly as it appears in the name, e.g. `DDP5.1.Atmos`.
+    pub fn audio_raw(&self) -> Option<&str> {
+        self.audio_raw.as_deref()
+    }
+    /// HDR formats detected, in order of appearance, e.g. `DV HDR10` ->
+    /// `[DolbyVision, Hdr10]`. Empty when the name does not say.
+    pub fn dynamic_range(&self) -> &[DynamicRange] {
+        &self.dynamic_range
+    }
+    /// Bits per color channel, e.g. 10 for `10bit` or `Hi10P`.
+    pub fn bit_depth(&self) -> Option<u8> {
+        self.bit_depth
+    }
+    /// Service a WEB release was captured from, e.g. `AMZN`.
+    pub fn streaming_service(&self) -> Option<StreamingService> {
+        self.streaming_service
     }
     pub fn group(&self) -> Option<&str> {
         self.group.as_deref()
@@ -133,17 +628,42 @@ impl Metadata {
     pub fn imdb_tag(&self) -> Option<&str> {
         self.imdb.as_deref()
     }
+    /// CRC32 of the file contents, as embedded in `[A1B2C3D4]`.
+    pub fn checksum(&self) -> Option<u32> {
+        self.checksum
+    }
+    /// Computes the CRC32 of `reader` and compares it to `checksum()`.
+    /// Returns `None`, without reading anything, when the name has no
+    /// checksum.
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("[Group] Title - 01 [CBF43926].mkv").unwrap();
+    /// assert!(m.verify_checksum(&b"123456789"[..]).unwrap().unwrap());
+    ///```
+    pub fn verify_checksum<R: Read>(&self, reader: R) -> Option<io::Result<bool>> {
+        let expected = self.checksum?;
+        Some(checksum::crc32(reader).map(|crc| crc == expected))
+    }
+    /// Cut or edition of a film, e.g. `Director's Cut` or `IMAX`.
+    pub fn edition(&self) -> Option<Edition> {
+        self.edition
+    }
     pub fn extended(&self) -> bool {
         self.extended
     }
     pub fn hardcoded(&self) -> bool {
         self.hardcoded
     }
+    /// Scene tags such as `PROPER`, `REAL` or `NFOFIX`.
+    pub fn flags(&self) -> ReleaseFlags {
+        self.flags
+    }
     pub fn proper(&self) -> bool {
-        self.proper
+        self.flags.contains(ReleaseFlags::PROPER)
     }
     pub fn repack(&self) -> bool {
-        self.repack
+        self.flags.contains(ReleaseFlags::REPACK)
     }
     pub fn widescreen(&self) -> bool {
         self.widescreen
@@ -157,243 +677,907 @@ impl Metadata {
     pub fn extension(&self) -> Option<&str> {
         self.extension.as_deref()
     }
+    /// Whether the name has a season, an absolute episode number or, for
+    /// daily shows, an air date.
     pub fn is_show(&self) -> bool {
-        self.season.is_some()
+        self.season.is_some() || self.absolute_episode.is_some() || self.is_daily()
+    }
+    /// Whether episodes are identified by air date rather than number.
+    pub fn is_daily(&self) -> bool {
+        self.air_date.is_some()
+    }
+    /// Whether the release holds whole seasons rather than single episodes.
+    pub fn is_season_pack(&self) -> bool {
+        !self.seasons.is_empty() && self.episodes.is_empty()
+    }
+    /// Whether the name says `Complete Series`. A bare `COMPLETE` only
+    /// means the season is complete and does not count.
+    pub fn is_complete_series(&self) -> bool {
+        self.complete_series
     }
     pub fn is_special(&self) -> bool {
         self.season.map(|s| s < 1).unwrap_or(false)
     }
+    /// The first language tag exactly as it appears in the name, e.g.
+    /// `TRUEFRENCH`.
     pub fn language(&self) -> Option<&str> {
         self.language.as_deref()
     }
+    /// Audio languages, in order of appearance, e.g. `[Russian, English]` for
+    /// `rus.eng`.
+    pub fn languages(&self) -> &[Language] {
+        &self.languages
+    }
+    /// Subtitle tracks announced in the name, e.g. `NLSubs` or `VOSTFR`. For
+    /// sidecar files parsed with `Metadata::from_subtitle_file` this is the
+    /// track the file holds.
+    pub fn subtitles(&self) -> &[Subtitle] {
+        &self.subtitles
+    }
+    /// DVD region code, e.g. `R5`.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+    /// Container format spelled out in the name, e.g. `MKV`.
+    pub fn container(&self) -> Option<&str> {
+        self.container.as_deref()
+    }
+    /// Payload size given in the name, e.g. `750MB`, in bytes.
+    pub fn size_bytes(&self) -> Option<u64> {
+        self.size
+    }
+    /// Tracker or website from a leading bracketed tag, e.g. `www.UsaBit.com`
+    /// for `[ www.UsaBit.com ] - My Cousin Vinny`.
+    pub fn website(&self) -> Option<&str> {
+        self.website.as_deref()
+    }
+    /// Values extracted by the custom patterns registered on the `Parser`,
+    /// keyed by pattern name.
+    pub fn extra(&self) -> &BTreeMap<String, String> {
+        &self.extra
+    }
+    /// Byte spans of the matches that produced each field, keyed by field
+    /// name (`"title"`, `"season"`, `"quality"`, ...).
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let name = "narcos.s01e10.1080p.bluray.x264-rovers";
+    /// let m = Metadata::from(name).unwrap();
+    /// let span = m.span("resolution").unwrap();
+    /// assert_eq!(&name[span.start..span.end], "1080p");
+    ///```
+    pub fn spans(&self) -> &BTreeMap<String, Span> {
+        &self.spans
+    }
+    pub fn span(&self, field: &str) -> Option<Span> {
+        self.spans.get(field).copied()
+    }
+    /// For `Metadata::from_path`, the path component `field` was read from,
+    /// keyed like `spans()`. The span of the field is relative to that
+    /// component.
+    pub fn source(&self, field: &str) -> Option<Source> {
+        self.sources.get(field).copied()
+    }
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+
+    /// Fills the fields `self` lacks with those of `other`, recording
+    /// `source` as their origin. A torrent passes on none of the fields
+    /// describing a single file, its episodes or checksum being those of
+    /// the whole batch.
+    pub(crate) fn inherit(&mut self, other: &Metadata, source: Source) {
+        fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+            *value == T::default()
+        }
+        const FILE_FIELDS: &[&str] = &[
+            "episode",
+            "episode_title",
+            "absolute_episode",
+            "part",
+            "checksum",
+            "size",
+            "extension",
+        ];
+        macro_rules! inherit {
+            ($($key:literal => $first:ident $(, $field:ident)*;)*) => {$(
+                if is_default(&self.$first)
+                    && !is_default(&other.$first)
+                    && !(source == Source::Torrent && FILE_FIELDS.contains(&$key))
+                {
+                    self.$first = other.$first.clone();
+                    $(self.$field = other.$field.clone();)*
+                    if let Some(&span) = other.spans.get($key) {
+                        self.spans.insert($key.to_string(), span);
+                    }
+                    self.sources.insert($key.to_string(), source);
+                }
+            )*};
+        }
+        inherit!(
+            "title" => title;
+            "season" => season, seasons;
+            "complete_series" => complete_series;
+            "episode" => episode, episodes;
+            "episode_title" => episode_title;
+            "absolute_episode" => absolute_episode, release_version;
+            "year" => year;
+            "air_date" => air_date;
+            "part" => part, total_parts;
+            "disc" => disc;
+            "resolution" => resolution, resolution_raw;
+            "quality" => quality, quality_raw;
+            "codec" => codec, codec_raw;
+            "audio" => audio, audio_raw;
+            "dynamic_range" => dynamic_range;
+            "bit_depth" => bit_depth;
+            "streaming_service" => streaming_service;
+            "group" => group;
+            "country" => country;
+            "edition" => edition;
+            "extended" => extended;
+            "hardcoded" => hardcoded;
+            "release_flags" => flags;
+            "widescreen" => widescreen;
+            "unrated" => unrated;
+            "three_d" => three_d;
+            "imdb" => imdb;
+            "checksum" => checksum;
+            "extension" => extension;
+            "language" => language, languages;
+            "subtitles" => subtitles;
+            "region" => region;
+            "container" => container;
+            "size" => size;
+            "website" => website;
+        );
+        for (key, value) in &other.extra {
+            self.extra
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        if !self.title.is_empty() {
+            self.warnings.retain(|w| *w != ParseWarning::MissingTitle);
+        }
+    }
 }
 
 impl FromStr for Metadata {
-    type Err = ErrorMatch;
+    type Err = ParseError;
 
     fn from_str(name: &str) -> Result<Self, Self::Err> {
-        let mut title_start = 0;
-        let mut title_end = name.len();
-        let mut episodes: Vec<i32> = Vec::new();
-        let interim_last_episode;
+        Parser::default().parse(name)
+    }
+}
 
-        let season = check_pattern_and_extract(
-            &pattern::SEASON,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| {
-                caps.name("short")
-                    .or_else(|| caps.name("long"))
-                    .or_else(|| caps.name("dash"))
-                    .or_else(|| caps.name("collection"))
-                    .map(|m| m.as_str())
+/// Parses the file name of `path` and merges in its season and show folders,
+/// see `Metadata::from_path`.
+pub(crate) fn parse_path(parser: &Parser, path: &Path) -> Metadata {
+    parse_path_in(parser, path, None)
+}
+
+/// See `Metadata::from_torrent`.
+pub(crate) fn parse_torrent<'f, P: AsRef<Path>>(
+    parser: &Parser,
+    name: &str,
+    files: &'f [P],
+) -> Vec<(&'f Path, Metadata)> {
+    let torrent = parser.parse_lenient(name);
+    files
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|file| !is_extra(file))
+        .map(|file| (file, parse_path_in(parser, file, Some(&torrent))))
+        .collect()
+}
+
+const INFO_EXTENSIONS: &[&str] = &["nfo", "sfv", "txt", "url"];
+
+/// Whether `file` is a sample, an NFO or an extra rather than part of the
+/// release.
+fn is_extra(file: &Path) -> bool {
+    let extension = file.extension().map(|e| e.to_string_lossy().to_lowercase());
+    if extension.is_some_and(|e| INFO_EXTENSIONS.contains(&e.as_str())) {
+        return true;
+    }
+    let stem = file.file_stem().map(|s| s.to_string_lossy());
+    let folders = file
+        .ancestors()
+        .skip(1)
+        .filter_map(Path::file_name)
+        .map(|folder| folder.to_string_lossy());
+    stem.into_iter().chain(folders).any(|name| {
+        pattern::SAMPLE.captures(&name).is_some() || pattern::EXTRAS.captures(&name).is_some()
+    })
+}
+
+/// Like `parse_path`, with `torrent` the metadata of the torrent holding the
+/// file, merged in after the season folder.
+fn parse_path_in(parser: &Parser, path: &Path, torrent: Option<&Metadata>) -> Metadata {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let mut folders = path
+        .ancestors()
+        .skip(1)
+        .filter_map(Path::file_name)
+        .map(|folder| folder.to_string_lossy());
+    let folder = folders.next();
+    let season_folder = folder
+        .as_deref()
+        .and_then(|f| parse_season_folder(parser, f));
+    let show_folder = if season_folder.is_some() {
+        folders.next().map(|f| parse_show_name(parser, &f))
+    } else {
+        folder.map(|f| parser.parse_lenient(&f))
+    };
+    // Inside a torrent, its name outranks a subfolder such as `Subs` or
+    // `Extras`; a season folder still comes first.
+    let show_folder = show_folder.map(|m| (m, Source::ShowFolder));
+    let torrent = torrent.map(|m| (m.clone(), Source::Torrent));
+    let folders: Vec<(Metadata, Source)> = season_folder
+        .map(|m| (m, Source::SeasonFolder))
+        .into_iter()
+        .chain(torrent)
+        .chain(show_folder)
+        .collect();
+    let folder_season = folders.iter().find_map(|(m, _)| m.season);
+
+    let mut m = parser.parse_lenient(&file_name);
+    if folder_season.is_some() && m.season.is_none() && m.episode.is_none() {
+        if let Some(leading) = leading_episode(parser, &file_name, &m) {
+            m = leading;
+        }
+    }
+    m.sources = m
+        .spans
+        .keys()
+        .map(|key| (key.clone(), Source::FileName))
+        .collect();
+    for (folder, source) in &folders {
+        m.inherit(folder, *source);
+    }
+    m
+}
+
+/// Reads `Season 02`, `S02` or `Specials` as season 2, 2 and 0.
+fn parse_season_folder(parser: &Parser, folder: &str) -> Option<Metadata> {
+    if !parser.is_enabled(Field::Season) {
+        return None;
+    }
+    let caps = pattern::SEASON_FOLDER.captures(folder)?;
+    let (season, span) = match caps.name("season") {
+        Some(m) => (m.as_str().parse().ok()?, Span::new(m.start(), m.end())),
+        None => (0, Span::new(0, folder.len())),
+    };
+    let mut spans = BTreeMap::new();
+    spans.insert(Field::Season.to_string(), span);
+    Some(Metadata {
+        season: Some(season),
+        seasons: vec![season],
+        spans,
+        ..Metadata::default()
+    })
+}
+
+/// Reads the folder above a season folder, such as `Breaking Bad (2008)` or
+/// `The 100`, as a show name: only its title and year.
+fn parse_show_name(parser: &Parser, folder: &str) -> Metadata {
+    let mut m = Metadata::default();
+    let year = pattern::YEAR
+        .captures(folder)
+        .and_then(|caps| caps.name("year"))
+        .filter(|year| year.start() > 0 && parser.is_enabled(Field::Year));
+    if let Some(year) = year {
+        m.year = year.as_str().parse().ok();
+        m.spans
+            .insert(Field::Year.to_string(), Span::new(year.start(), year.end()));
+    }
+    let title = folder[..year.map_or(folder.len(), |year| year.start())]
+        .trim_end_matches(|c| " ._-([".contains(c));
+    if title.is_empty() {
+        m.warnings.push(ParseWarning::MissingTitle);
+        return m;
+    }
+    m.spans
+        .insert("title".to_string(), Span::new(0, title.len()));
+    let title = match !title.contains(' ') && title.contains('.') {
+        true => Cow::Owned(title.replace('.', " ")),
+        false => Cow::Borrowed(title),
+    };
+    m.title = title
+        .replace('_', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    m
+}
+
+/// Reads a file name such as `05 - Breakage.mkv` as episode 5 titled
+/// `Breakage`. `m` is the metadata of the whole file name.
+fn leading_episode(parser: &Parser, file_name: &str, m: &Metadata) -> Option<Metadata> {
+    let caps = pattern::LEADING_EPISODE.captures(file_name)?;
+    let number = caps.name("episode")?;
+    let episode = number.as_str().parse().ok()?;
+    // Keep the dot of the extension in `05.mkv`.
+    let offset = m
+        .span(Field::Extension.as_str())
+        .map_or(caps[0].len(), |span| min(span.start, caps[0].len()));
+
+    let mut leading = parser.parse_lenient(&file_name[offset..]);
+    for span in leading.spans.values_mut() {
+        span.start += offset;
+        span.end += offset;
+    }
+    if let Some(span) = leading.spans.remove("title") {
+        leading.spans.insert("episode_title".to_string(), span);
+    }
+    leading.episode_title = Some(std::mem::take(&mut leading.title)).filter(|t| !t.is_empty());
+    if !leading.warnings.contains(&ParseWarning::MissingTitle) {
+        leading.warnings.push(ParseWarning::MissingTitle);
+    }
+    leading.episode = Some(episode);
+    leading.episodes = vec![episode];
+    leading.spans.insert(
+        Field::Episode.to_string(),
+        Span::new(number.start(), number.end()),
+    );
+    Some(leading)
+}
+
+const SUBTITLE_EXTENSIONS: &[&str] = &[
+    "srt", "ass", "ssa", "sub", "idx", "sup", "vtt", "ttml", "dfxp", "smi", "svb",
+];
+
+/// Parses a sidecar subtitle file name: the language and flags in front of
+/// the extension describe the track, the rest is parsed like a release name.
+/// A name without a subtitle extension is only parsed as a release name.
+pub(crate) fn parse_subtitle(parser: &Parser, name: &str) -> Metadata {
+    let (mut stem, extension) = match name.rsplit_once('.') {
+        Some((stem, ext)) if SUBTITLE_EXTENSIONS.contains(&ext.to_lowercase().as_str()) => {
+            (stem, ext)
+        }
+        _ => return parse(parser, name),
+    };
+    let tags_end = stem.len();
+    let mut subtitle = Subtitle::default();
+    while let Some((rest, tag)) = stem.rsplit_once('.') {
+        match tag.to_lowercase().as_str() {
+            "forced" => subtitle.forced = true,
+            "sdh" | "cc" => subtitle.sdh = true,
+            tag if subtitle.language.is_none() => match sidecar_language(tag) {
+                Some(language) => subtitle.language = Some(language),
+                None => break,
             },
+            _ => break,
+        }
+        stem = rest;
+    }
+
+    let mut m = parse(parser, stem);
+    if stem.len() < tags_end {
+        m.spans.insert(
+            Field::Subtitles.to_string(),
+            Span::new(stem.len(), tags_end),
+        );
+    }
+    m.spans.insert(
+        Field::Extension.to_string(),
+        Span::new(tags_end, name.len()),
+    );
+    m.extension = Some(extension.to_string());
+    m.subtitles = vec![subtitle];
+    m
+}
+
+pub(crate) fn parse(parser: &Parser, name: &str) -> Metadata {
+    let mut bounds = Bounds::new(parser, name);
+    let (leading_bracket, anime) = leading_bracket(parser, name);
+    let bracket_field = match leading_bracket {
+        LeadingBracket::Website => Field::Website,
+        LeadingBracket::Group => Field::Group,
+    };
+    if parser.is_enabled(bracket_field) {
+        let bracket = pattern::WEBSITE.captures(name);
+        bounds
+            .ignored
+            .extend(bracket.map(|caps| Span::new(0, caps[0].len())));
+    }
+    let checksum = check_pattern_where(
+        Field::Checksum,
+        &pattern::CHECKSUM,
+        name,
+        &mut bounds,
+        |caps| !is_compact_date(&caps["crc"]),
+    )
+    .and_then(|caps| u32::from_str_radix(&caps["crc"], 16).ok());
+    bounds
+        .ignored
+        .extend(bounds.spans.get(Field::Checksum.as_str()).copied());
+    let disc = check_pattern(Field::Disc, &pattern::DISC, name, &mut bounds)
+        .and_then(|caps| caps["disc"].parse().ok());
+    // Parts before the year stay in the title, as in `Kill.Bill.Vol.1.2003`.
+    let year_start = pattern::YEAR
+        .captures_where(name, |caps| bounds.keeps(caps))
+        .map_or(0, |caps| caps.get(0).unwrap().start());
+    let valid_part = |caps: &Captures<'_>| {
+        part_number(&caps["part"]).is_some()
+            && caps
+                .name("total")
+                .is_none_or(|m| part_number(m.as_str()).is_some())
+    };
+    let part = check_pattern_where(Field::Part, &pattern::PART, name, &mut bounds, |caps| {
+        caps.get(0).unwrap().start() > year_start && valid_part(caps)
+    })
+    .or_else(|| {
+        if !parser.is_enabled(Field::Part) {
+            return None;
+        }
+        let caps =
+            pattern::PART.captures_where(name, |caps| bounds.keeps(caps) && valid_part(caps))?;
+        let cap = caps.get(0).unwrap();
+        bounds.spans.insert(
+            Field::Part.as_str().to_string(),
+            Span::new(cap.start(), cap.end()),
         );
+        Some(caps)
+    });
+    let total_parts = part
+        .as_ref()
+        .and_then(|caps| caps.name("total"))
+        .and_then(|m| part_number(m.as_str()));
+    let part = part.and_then(|caps| part_number(&caps["part"]));
+    for field in [Field::Disc, Field::Part] {
+        let span = bounds.spans.get(field.as_str()).copied();
+        bounds.ignored.extend(span);
+    }
+    let mut episodes: Vec<i32> = Vec::new();
+    let interim_last_episode;
+
+    let mut seasons = check_pattern(Field::Season, &pattern::SEASON_RANGE, name, &mut bounds)
+        .map(|caps| season_range(&caps))
+        .unwrap_or_default();
+    let season = if seasons.is_empty() {
+        check_pattern_and_extract(Field::Season, &pattern::SEASON, name, &mut bounds, |caps| {
+            caps.name("short")
+                .or_else(|| caps.name("long"))
+                .or_else(|| caps.name("dash"))
+                .or_else(|| caps.name("collection"))
+                .map(|m| m.as_str())
+        })
+        .map(|s| s.parse().unwrap())
+    } else {
+        seasons.first().copied()
+    };
+    if seasons.is_empty() {
+        seasons.extend(season);
+    }
+    let complete_series = check_pattern(
+        Field::CompleteSeries,
+        &pattern::COMPLETE_SERIES,
+        name,
+        &mut bounds,
+    );
 
-        let episode = check_pattern_and_extract(
-            &pattern::EPISODE,
+    let episode = check_pattern_and_extract(
+        Field::Episode,
+        &pattern::EPISODE,
+        name,
+        &mut bounds,
+        |caps| {
+            caps.name("short")
+                .or_else(|| caps.name("cross"))
+                .or_else(|| caps.name("dash"))
+                .map(|m| m.as_str())
+        },
+    );
+    // Only look for a last episode if pattern::EPISODE returned a value.
+    if let Some(first_episode) = episode {
+        episodes.push(first_episode.parse().unwrap());
+        interim_last_episode = check_pattern_and_extract(
+            Field::Episode,
+            &pattern::LAST_EPISODE,
             name,
-            &mut title_start,
-            &mut title_end,
-            |caps| {
-                caps.name("short")
-                    .or_else(|| caps.name("cross"))
-                    .or_else(|| caps.name("dash"))
-                    .map(|m| m.as_str())
-            },
+            &mut bounds,
+            |caps| caps.get(1).map(|m| m.as_str()),
         );
-        // Only look for a last episode if pattern::EPISODE returned a value.
-        if let Some(first_episode) = episode {
-            episodes.push(first_episode.parse().unwrap());
-            interim_last_episode = check_pattern_and_extract(
-                &pattern::LAST_EPISODE,
-                name,
-                &mut title_start,
-                &mut title_end,
-                |caps| caps.get(1).map(|m| m.as_str()),
-            );
-            if let Some(last_episode) = interim_last_episode {
-                // Sanity check that last_episode does not contain a value or 0 (Zero)
-                if last_episode.len() == 1 && last_episode.contains('0') {
-                    // Treat a string ending with '0' (zero) as invalid and skip further work
-                } else {
-                    // Populate Vec with each episode number
-                    for number_of_episode in
-                        first_episode.parse::<i32>().unwrap() + 1..=last_episode.parse().unwrap()
-                    {
-                        episodes.push(number_of_episode);
-                    }
+        if let Some(last_episode) = interim_last_episode {
+            // Sanity check that last_episode does not contain a value or 0 (Zero)
+            if last_episode.len() == 1 && last_episode.contains('0') {
+                // Treat a string ending with '0' (zero) as invalid and skip further work
+            } else {
+                // Populate Vec with each episode number
+                for number_of_episode in
+                    first_episode.parse::<i32>().unwrap() + 1..=last_episode.parse().unwrap()
+                {
+                    episodes.push(number_of_episode);
                 }
             }
         }
-        let year = check_pattern_and_extract(
-            &pattern::YEAR,
+    }
+    let mut absolute_episode = None;
+    let mut release_version = None;
+    if anime && season.is_none() && episode.is_none() {
+        if let Some(caps) = check_pattern_where(
+            Field::AbsoluteEpisode,
+            &pattern::ABSOLUTE_EPISODE,
             name,
-            &mut title_start,
-            &mut title_end,
-            |caps: Captures<'_>| caps.name("year").map(|m| m.as_str()),
-        );
+            &mut bounds,
+            |caps| !is_year_episode(caps),
+        ) {
+            let number = |group| caps.name(group)?.as_str().parse::<i32>().ok();
+            let first = number("first").or_else(|| number("batch_first"));
+            let last = number("last").or_else(|| number("batch_last"));
+            episodes.extend(first);
+            if let (Some(first), Some(last)) = (first, last) {
+                episodes.extend(first + 1..=last);
+            }
+            absolute_episode = first;
+            release_version = caps.name("version").and_then(|m| m.as_str().parse().ok());
+        }
+    }
+    let mut warnings = Vec::new();
+    let audio_spans: Vec<_> = pattern::AUDIO
+        .captures_iter(name)
+        .filter_map(|caps| caps.get(0))
+        .map(|m| Span::new(m.start(), m.end()))
+        .collect();
+    let air_date = check_pattern_where(
+        Field::AirDate,
+        &pattern::AIR_DATE,
+        name,
+        &mut bounds,
+        |caps| !is_channel_layout(caps, &audio_spans),
+    )
+    .and_then(|caps| air_date(&caps, &mut warnings));
+    let year = check_pattern_and_extract(
+        Field::Year,
+        &pattern::YEAR,
+        name,
+        &mut bounds,
+        |caps: Captures<'_>| caps.name("year").map(|m| m.as_str()),
+    );
 
-        let resolution = check_pattern_and_extract(
-            &pattern::RESOLUTION,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let quality = check_pattern_and_extract(
-            &pattern::QUALITY,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let codec = check_pattern_and_extract(
-            &pattern::CODEC,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let audio = check_pattern_and_extract(
-            &pattern::AUDIO,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let group = check_pattern_and_extract(
-            &pattern::GROUP,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(2).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let imdb = check_pattern_and_extract(
-            &pattern::IMDB,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let extension = check_pattern_and_extract(
-            &pattern::FILE_EXTENSION,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(1).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let country = check_pattern_and_extract(
-            &pattern::COUNTRY,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.name("country").map(|m| m.as_str()),
-        )
+    let resolution = check_pattern_and_extract(
+        Field::Resolution,
+        &pattern::RESOLUTION,
+        name,
+        &mut bounds,
+        |caps| caps.get(0).map(|m| m.as_str()),
+    )
+    .map(String::from);
+    let quality = check_pattern_and_extract(
+        Field::Quality,
+        &pattern::QUALITY,
+        name,
+        &mut bounds,
+        |caps| caps.get(0).map(|m| m.as_str()),
+    )
+    .map(String::from);
+    let codec =
+        check_pattern_and_extract(Field::Codec, &pattern::CODEC, name, &mut bounds, |caps| {
+            caps.get(0).map(|m| m.as_str())
+        })
         .map(String::from);
-        let language = check_pattern_and_extract(
-            &pattern::LANGUAGE,
+    // Likewise for codecs that read as words, as in `Mr.Hollands.Opus.1995`.
+    let marker_start = bounds.marker_start();
+    let audio_caps =
+        check_pattern_where(Field::Audio, &pattern::AUDIO, name, &mut bounds, |caps| {
+            let format = caps.name("format").unwrap();
+            let word = ["opus", "dd", "flac", "pcm", "lpcm"]
+                .contains(&format.as_str().to_lowercase().as_str());
+            !word || format.start() > marker_start
+        });
+    let audio = audio_caps.as_ref().and_then(Audio::from_captures);
+    let audio_raw = audio_caps.map(|caps| caps[0].to_string());
+    let dynamic_range = DynamicRange::from_matches(
+        check_pattern_all(
+            Field::DynamicRange,
+            &pattern::DYNAMIC_RANGE,
             name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|s| s.as_str()),
+            &mut bounds,
         )
+        .iter()
+        .filter_map(|caps| caps.get(0))
+        .map(|m| m.as_str()),
+    );
+    let bit_depth = check_pattern_and_extract(
+        Field::BitDepth,
+        &pattern::BIT_DEPTH,
+        name,
+        &mut bounds,
+        |caps| {
+            caps.name("depth")
+                .or_else(|| caps.name("hi"))
+                .map(|m| m.as_str())
+        },
+    );
+    let streaming_service = check_pattern(
+        Field::StreamingService,
+        &pattern::STREAMING_SERVICE,
+        name,
+        &mut bounds,
+    )
+    .and_then(|caps| {
+        let tag = caps.name("service").or_else(|| caps.name("short"))?;
+        // Keep the trailing WEB out of the span, it belongs to the quality.
+        bounds.spans.insert(
+            Field::StreamingService.to_string(),
+            Span::new(tag.start(), tag.end()),
+        );
+        StreamingService::from_tag(tag.as_str())
+    });
+    // Tags that also read as words, such as `REAL` or `DAN`, are only trusted
+    // after the year, season or episode, as in `REAL.STEEL.2011`.
+    let marker_start = bounds.marker_start();
+    // Tags such as `ENG.Subs` or `Multi-Subs` are not audio languages.
+    let subtitles: Vec<_> =
+        check_pattern_all(Field::Subtitles, &pattern::SUBTITLES, name, &mut bounds)
+            .iter()
+            .map(|caps| {
+                let m = caps.get(0).unwrap();
+                bounds.ignored.push(Span::new(m.start(), m.end()));
+                subtitle_tag(caps)
+            })
+            .collect();
+    // Likewise for codes and spelled out names, as in `DAN.IN.REAL.LIFE.2007`
+    // or `The.Italian.Job.2003`.
+    let language_matches = check_pattern_all_where(
+        Field::Language,
+        &pattern::LANGUAGE,
+        name,
+        &mut bounds,
+        |caps| {
+            caps.name("code")
+                .or_else(|| caps.name("name"))
+                .is_none_or(|m| m.start() > marker_start)
+        },
+    );
+    let language = language_matches.first().map(|caps| caps[0].to_string());
+    let languages = Language::from_matches(language_matches.iter().map(|caps| &caps[0]));
+
+    // A dash inside a season range, a date or a tag such as `ENG-ITA` does not
+    // introduce the group.
+    for field in [Field::Season, Field::AirDate, Field::Audio] {
+        let span = bounds.spans.get(field.as_str()).copied();
+        bounds.ignored.extend(span);
+    }
+    bounds.ignored.extend(
+        language_matches
+            .iter()
+            .filter_map(|caps| caps.get(0))
+            .map(|m| Span::new(m.start(), m.end())),
+    );
+    // Nor is text already read as another field, as the `S01E01` of
+    // `Re Zero 2020 - S01E01`.
+    let claimed: Vec<Span> = bounds.spans.values().copied().collect();
+    let group = check_pattern_where(Field::Group, &pattern::GROUP, name, &mut bounds, |caps| {
+        let start = caps.get(2).unwrap().start();
+        !claimed
+            .iter()
+            .any(|span| span.start <= start && start < span.end)
+    })
+    .map(|caps| caps[2].to_string());
+    let imdb = check_pattern_and_extract(Field::Imdb, &pattern::IMDB, name, &mut bounds, |caps| {
+        caps.get(0).map(|m| m.as_str())
+    })
+    .map(String::from);
+    // Not the `ESub` of `Movie.2019.1080p.ESub`.
+    let subtitles_span = bounds.spans.get(Field::Subtitles.as_str()).copied();
+    let extension = check_pattern_where(
+        Field::Extension,
+        &pattern::FILE_EXTENSION,
+        name,
+        &mut bounds,
+        |caps| {
+            let start = caps.get(1).unwrap().start();
+            subtitles_span.is_none_or(|span| start < span.start || span.end <= start)
+        },
+    )
+    .map(|caps| caps[1].to_string());
+    let country = check_pattern_and_extract(
+        Field::Country,
+        &pattern::COUNTRY,
+        name,
+        &mut bounds,
+        |caps| caps.name("country").map(|m| m.as_str()),
+    )
+    .map(String::from);
+    // Like parts, editions before the year belong to the title.
+    let edition = check_pattern_where(
+        Field::Edition,
+        &pattern::EDITION,
+        name,
+        &mut bounds,
+        |caps| edition_match(caps).is_some_and(|m| m.start() > year_start),
+    )
+    .and_then(|caps| {
+        let edition = edition_match(&caps)?;
+        bounds.spans.insert(
+            Field::Edition.to_string(),
+            Span::new(edition.start(), edition.end()),
+        );
+        Edition::from_raw(edition.as_str())
+    });
+    let extended = check_pattern(Field::Extended, &pattern::EXTENDED, name, &mut bounds);
+    let hardcoded = check_pattern(Field::Hardcoded, &pattern::HARDCODED, name, &mut bounds);
+    let proper = check_pattern(Field::Proper, &pattern::PROPER, name, &mut bounds);
+    let repack = check_pattern(Field::Repack, &pattern::REPACK, name, &mut bounds);
+    let mut flags = check_pattern_all_where(
+        Field::ReleaseFlags,
+        &pattern::RELEASE_FLAGS,
+        name,
+        &mut bounds,
+        |caps| caps.get(0).unwrap().start() > marker_start,
+    )
+    .iter()
+    .filter_map(|caps| ReleaseFlags::from_name(&caps[0]))
+    .fold(ReleaseFlags::empty(), |flags, flag| flags | flag);
+    if proper.is_some() {
+        flags |= ReleaseFlags::PROPER;
+    }
+    if repack.is_some() {
+        flags |= ReleaseFlags::REPACK;
+    }
+    let widescreen = check_pattern(Field::Widescreen, &pattern::WIDESCREEN, name, &mut bounds);
+    let unrated = check_pattern(Field::Unrated, &pattern::UNRATED, name, &mut bounds);
+    let three_d = check_pattern(Field::ThreeD, &pattern::THREE_D, name, &mut bounds);
+
+    let region =
+        check_pattern_and_extract(Field::Region, &pattern::REGION, name, &mut bounds, |caps| {
+            caps.get(0).map(|m| m.as_str())
+        })
         .map(String::from);
+    let container = check_pattern_and_extract(
+        Field::Container,
+        &pattern::CONTAINER,
+        name,
+        &mut bounds,
+        |caps| caps.get(0).map(|m| m.as_str()),
+    )
+    .map(String::from);
+    let size = check_pattern(Field::Size, &pattern::SIZE, name, &mut bounds)
+        .as_ref()
+        .and_then(size_bytes);
+    check_pattern(Field::Garbage, &pattern::GARBAGE, name, &mut bounds);
+    let mut extra = BTreeMap::new();
+    for custom in parser.custom_patterns() {
+        if let Some(caps) = custom
+            .pattern
+            .captures_where(name, |caps| bounds.keeps(caps))
+        {
+            bounds.update(&custom.name, &custom.pattern, &caps);
+            if let Some(value) = (custom.extract)(&caps) {
+                extra.insert(custom.name.clone(), value);
+            }
+        }
+    }
 
-        let extended = check_pattern(&pattern::EXTENDED, name, &mut title_start, &mut title_end);
-        let hardcoded = check_pattern(&pattern::HARDCODED, name, &mut title_start, &mut title_end);
-        let proper = check_pattern(&pattern::PROPER, name, &mut title_start, &mut title_end);
-        let repack = check_pattern(&pattern::REPACK, name, &mut title_start, &mut title_end);
-        let widescreen =
-            check_pattern(&pattern::WIDESCREEN, name, &mut title_start, &mut title_end);
-        let unrated = check_pattern(&pattern::UNRATED, name, &mut title_start, &mut title_end);
-        let three_d = check_pattern(&pattern::THREE_D, name, &mut title_start, &mut title_end);
-
-        let region = check_pattern(&pattern::REGION, name, &mut title_start, &mut title_end);
-        let container = check_pattern(&pattern::CONTAINER, name, &mut title_start, &mut title_end);
-        let garbage = check_pattern(&pattern::GARBAGE, name, &mut title_start, &mut title_end);
-        let website = check_pattern(&pattern::WEBSITE, name, &mut title_start, &mut title_end);
-
-        if title_start >= title_end {
-            return Err(ErrorMatch::new(vec![
-                ("season", season.map(String::from)),
-                ("episode", episode.map(String::from)),
-                ("year", year.map(String::from)),
-                ("extension", extension.map(String::from)),
-                ("resolution", resolution),
-                ("quality", quality),
-                ("codec", codec),
-                ("audio", audio),
-                ("group", group),
-                ("country", country),
-                ("imdb", imdb),
-                ("language", language),
-                ("extended", capture_to_string(extended)),
-                ("proper", capture_to_string(proper)),
-                ("repack", capture_to_string(repack)),
-                ("widescreen", capture_to_string(widescreen)),
-                ("unrated", capture_to_string(unrated)),
-                ("three_d", capture_to_string(three_d)),
-                ("region", capture_to_string(region)),
-                ("container", capture_to_string(container)),
-                ("garbage", capture_to_string(garbage)),
-                ("website", capture_to_string(website)),
-            ]));
+    bounds.ignored.clear();
+    let (website, group) = match leading_bracket {
+        LeadingBracket::Website => {
+            let website = check_pattern_and_extract(
+                Field::Website,
+                &pattern::WEBSITE,
+                name,
+                &mut bounds,
+                |caps| caps.get(2).map(|m| m.as_str()),
+            )
+            .map(String::from);
+            (website, group)
+        }
+        LeadingBracket::Group => {
+            let group_span = bounds.spans.get(Field::Group.as_str()).copied();
+            let bracket = check_pattern_and_extract(
+                Field::Group,
+                &pattern::WEBSITE,
+                name,
+                &mut bounds,
+                |caps| caps.get(2).map(|m| m.as_str()),
+            )
+            .map(String::from);
+            // A trailing `-GROUP` names the release group more reliably than a
+            // bracketed tag.
+            if let (Some(_), Some(span)) = (&group, group_span) {
+                bounds.spans.insert(Field::Group.to_string(), span);
+            }
+            (None, group.or(bracket))
         }
+    };
 
-        let mut title = &name[title_start..title_end];
+    let mut spans = bounds.spans;
+    let episode_title = episode_title(name, &spans).map(|(title, span)| {
+        spans.insert("episode_title".to_string(), span);
+        title
+    });
+    let title = if bounds.title_start >= bounds.title_end {
+        warnings.push(ParseWarning::MissingTitle);
+        String::new()
+    } else {
+        let mut title = &name[bounds.title_start..bounds.title_end];
         if let Some(pos) = title.find('(') {
             title = title.split_at(pos).0;
         }
         title = title.trim_start_matches(" -");
         title = title.trim_end_matches(" -");
+        let separators = |c| " -._".contains(c);
+        let untrimmed = &name[bounds.title_start..bounds.title_end];
+        let title_offset =
+            bounds.title_start + untrimmed.len() - untrimmed.trim_start_matches(separators).len();
+        spans.insert(
+            "title".to_string(),
+            Span::new(
+                title_offset,
+                title_offset + title.trim_matches(separators).len(),
+            ),
+        );
         let title = match !title.contains(' ') && title.contains('.') {
             true => Cow::Owned(title.replace('.', " ")),
             false => Cow::Borrowed(title),
         };
-        let title = title
+        title
             .replace('_', " ")
             .replacen('(', "", 1)
             .replacen("- ", "", 1)
-            .trim()
-            .to_string();
-
-        Ok(Metadata {
-            title,
-            season: season.map(|s| s.parse().unwrap()),
-            episode: episode.map(|s| s.parse().unwrap()),
-            episodes,
-            year: year.map(|s| s.parse().unwrap()),
-            resolution,
-            quality,
-            codec,
-            audio,
-            group,
-            country,
-            extended: extended.is_some(),
-            hardcoded: hardcoded.is_some(),
-            proper: proper.is_some(),
-            repack: repack.is_some(),
-            widescreen: widescreen.is_some(),
-            unrated: unrated.is_some(),
-            three_d: three_d.is_some(),
-            imdb,
-            extension,
-            language,
-        })
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    Metadata {
+        title,
+        season,
+        seasons,
+        complete_series: complete_series.is_some(),
+        episode: episode.map(|s| s.parse().unwrap()).or(absolute_episode),
+        absolute_episode,
+        release_version,
+        part,
+        total_parts,
+        disc,
+        episode_title,
+        episodes,
+        year: air_date
+            .map(|date| date.year)
+            .or_else(|| year.map(|s| s.parse().unwrap())),
+        air_date,
+        resolution: resolution.as_deref().map(Resolution::from),
+        resolution_raw: resolution,
+        quality: quality.as_deref().map(Quality::from),
+        quality_raw: quality,
+        codec: codec.as_deref().map(VideoCodec::from),
+        codec_raw: codec,
+        audio,
+        audio_raw,
+        dynamic_range,
+        bit_depth: bit_depth.map(|s| s.parse().unwrap()),
+        streaming_service,
+        group,
+        country,
+        edition,
+        extended: extended.is_some(),
+        hardcoded: hardcoded.is_some(),
+        flags,
+        widescreen: widescreen.is_some(),
+        unrated: unrated.is_some(),
+        three_d: three_d.is_some(),
+        imdb,
+        checksum,
+        extension,
+        language,
+        languages,
+        subtitles,
+        region,
+        container,
+        size,
+        website,
+        extra,
+        spans,
+        sources: BTreeMap::new(),
+        warnings,
     }
 }
 
 impl TryFrom<&str> for Metadata {
-    type Error = ErrorMatch;
+    type Error = ParseError;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         Metadata::from_str(s)
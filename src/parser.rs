@@ -0,0 +1,305 @@
+use crate::error::{ParseError, ParseWarning};
+use crate::metadata::{self, Metadata};
+use crate::pattern::Pattern;
+use regex::Captures;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A field the parser knows how to extract. Used to switch individual
+/// extractors on and off with `ParserBuilder`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Field {
+    Season,
+    CompleteSeries,
+    Episode,
+    AbsoluteEpisode,
+    Year,
+    AirDate,
+    Part,
+    Disc,
+    Resolution,
+    Quality,
+    Codec,
+    Audio,
+    DynamicRange,
+    BitDepth,
+    StreamingService,
+    Group,
+    Imdb,
+    Checksum,
+    Extension,
+    Country,
+    Language,
+    Subtitles,
+    Edition,
+    Extended,
+    Hardcoded,
+    Proper,
+    Repack,
+    ReleaseFlags,
+    Widescreen,
+    Unrated,
+    ThreeD,
+    Region,
+    Container,
+    Size,
+    Garbage,
+    Website,
+}
+
+impl Field {
+    pub(crate) const ALL: [Field; 36] = [
+        Field::Season,
+        Field::CompleteSeries,
+        Field::Episode,
+        Field::AbsoluteEpisode,
+        Field::Year,
+        Field::AirDate,
+        Field::Part,
+        Field::Disc,
+        Field::Resolution,
+        Field::Quality,
+        Field::Codec,
+        Field::Audio,
+        Field::DynamicRange,
+        Field::BitDepth,
+        Field::StreamingService,
+        Field::Group,
+        Field::Imdb,
+        Field::Checksum,
+        Field::Extension,
+        Field::Country,
+        Field::Language,
+        Field::Subtitles,
+        Field::Edition,
+        Field::Extended,
+        Field::Hardcoded,
+        Field::Proper,
+        Field::Repack,
+        Field::ReleaseFlags,
+        Field::Widescreen,
+        Field::Unrated,
+        Field::ThreeD,
+        Field::Region,
+        Field::Container,
+        Field::Size,
+        Field::Garbage,
+        Field::Website,
+    ];
+
+    /// The name used as key in `Metadata::spans()`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Field::Season => "season",
+            Field::CompleteSeries => "complete_series",
+            Field::Episode => "episode",
+            Field::AbsoluteEpisode => "absolute_episode",
+            Field::Year => "year",
+            Field::AirDate => "air_date",
+            Field::Part => "part",
+            Field::Disc => "disc",
+            Field::Resolution => "resolution",
+            Field::Quality => "quality",
+            Field::Codec => "codec",
+            Field::Audio => "audio",
+            Field::DynamicRange => "dynamic_range",
+            Field::BitDepth => "bit_depth",
+            Field::StreamingService => "streaming_service",
+            Field::Group => "group",
+            Field::Imdb => "imdb",
+            Field::Checksum => "checksum",
+            Field::Extension => "extension",
+            Field::Country => "country",
+            Field::Language => "language",
+            Field::Subtitles => "subtitles",
+            Field::Edition => "edition",
+            Field::Extended => "extended",
+            Field::Hardcoded => "hardcoded",
+            Field::Proper => "proper",
+            Field::Repack => "repack",
+            Field::ReleaseFlags => "release_flags",
+            Field::Widescreen => "widescreen",
+            Field::Unrated => "unrated",
+            Field::ThreeD => "three_d",
+            Field::Region => "region",
+            Field::Container => "container",
+            Field::Size => "size",
+            Field::Garbage => "garbage",
+            Field::Website => "website",
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How to interpret a bracketed tag at the very start of the name, as in
+/// `[ www.UsaBit.com ] - My Cousin Vinny` or `[SubsPlease] Dr. Stone`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LeadingBracket {
+    /// The tag is the tracker or website the release was taken from, unless
+    /// the name follows fansub naming as in `[Group] Title - 24`.
+    #[default]
+    Website,
+    /// The tag is the release group.
+    Group,
+}
+
+/// A reusable parser configuration.
+///
+/// `Metadata::from` uses the default configuration, which runs every
+/// extractor.
+///```
+/// use torrent_name_parser::{Field, LeadingBracket, Parser};
+///
+/// let parser = Parser::builder()
+///     .disable(Field::Country)
+///     .leading_bracket(LeadingBracket::Group)
+///     .build();
+///
+/// let m = parser.parse("[SubsPlease] Dr. Stone S2 - 07 (1080p)").unwrap();
+/// assert_eq!(m.title(), "Dr. Stone");
+/// assert_eq!(m.group(), Some("SubsPlease"));
+///```
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+    disabled: HashSet<Field>,
+    leading_bracket: LeadingBracket,
+    custom_patterns: Vec<CustomPattern>,
+}
+
+type Extract = dyn Fn(&Captures<'_>) -> Option<String> + Send + Sync;
+
+/// A user supplied pattern, see `ParserBuilder::custom_pattern`.
+#[derive(Clone)]
+pub(crate) struct CustomPattern {
+    pub(crate) name: String,
+    pub(crate) pattern: Pattern,
+    pub(crate) extract: Arc<Extract>,
+}
+
+impl fmt::Debug for CustomPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CustomPattern")
+            .field("name", &self.name)
+            .field("pattern", &self.pattern)
+            .finish()
+    }
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Parser::default()
+    }
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder::default()
+    }
+
+    pub fn parse(&self, name: &str) -> Result<Metadata, ParseError> {
+        require_title(self.parse_lenient(name))
+    }
+    /// See `Metadata::from_subtitle_file`.
+    pub fn parse_subtitle(&self, name: &str) -> Result<Metadata, ParseError> {
+        require_title(metadata::parse_subtitle(self, name))
+    }
+    /// See `Metadata::from_path`.
+    pub fn parse_path(&self, path: &Path) -> Result<Metadata, ParseError> {
+        require_title(metadata::parse_path(self, path))
+    }
+    /// See `Metadata::from_torrent`.
+    pub fn parse_torrent<'f, P: AsRef<Path>>(
+        &self,
+        name: &str,
+        files: &'f [P],
+    ) -> Vec<(&'f Path, Metadata)> {
+        metadata::parse_torrent(self, name, files)
+    }
+    /// See `Metadata::from_lenient`.
+    pub fn parse_lenient(&self, name: &str) -> Metadata {
+        metadata::parse(self, name)
+    }
+
+    pub fn is_enabled(&self, field: Field) -> bool {
+        !self.disabled.contains(&field)
+    }
+    pub fn leading_bracket(&self) -> LeadingBracket {
+        self.leading_bracket
+    }
+    pub(crate) fn custom_patterns(&self) -> &[CustomPattern] {
+        &self.custom_patterns
+    }
+}
+
+fn require_title(m: Metadata) -> Result<Metadata, ParseError> {
+    if m.warnings().contains(&ParseWarning::MissingTitle) {
+        return Err(ParseError::MissingTitle(Box::new(m)));
+    }
+    Ok(m)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ParserBuilder {
+    parser: Parser,
+}
+
+impl ParserBuilder {
+    /// Skip extracting `field`. The matching text is then left in the title.
+    pub fn disable(mut self, field: Field) -> Self {
+        self.parser.disabled.insert(field);
+        self
+    }
+    pub fn enable(mut self, field: Field) -> Self {
+        self.parser.disabled.remove(&field);
+        self
+    }
+    pub fn leading_bracket(mut self, leading_bracket: LeadingBracket) -> Self {
+        self.parser.leading_bracket = leading_bracket;
+        self
+    }
+    /// Registers an additional pattern. When it matches, the value returned by
+    /// `extract` is stored in `Metadata::extra()` under `name`, and the match
+    /// shrinks the title like the built-in patterns do.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is a key of `Metadata::spans()` already used by a built-in
+    /// field, such as `title` or `edition`.
+    ///```
+    /// use regex::Regex;
+    /// use torrent_name_parser::{Parser, Pattern};
+    ///
+    /// let parser = Parser::builder()
+    ///     .custom_pattern(
+    ///         "gold",
+    ///         Pattern::new(Regex::new(r"\bGOLD\b").unwrap(), false, false, false),
+    ///         |caps| caps.get(0).map(|m| m.as_str().to_string()),
+    ///     )
+    ///     .build();
+    ///
+    /// let m = parser.parse("Hercules.2014.GOLD.1080p.WEB-DL").unwrap();
+    /// assert_eq!(m.extra().get("gold").map(String::as_str), Some("GOLD"));
+    ///```
+    pub fn custom_pattern<F>(mut self, name: &str, pattern: Pattern, extract: F) -> Self
+    where
+        F: Fn(&Captures<'_>) -> Option<String> + Send + Sync + 'static,
+    {
+        let built_in = ["title", "episode_title"].contains(&name)
+            || Field::ALL.iter().any(|field| field.as_str() == name);
+        assert!(!built_in, "`{}` is the name of a built-in field", name);
+        self.parser.custom_patterns.push(CustomPattern {
+            name: name.to_string(),
+            pattern,
+            extract: Arc::new(extract),
+        });
+        self
+    }
+    pub fn build(self) -> Parser {
+        self.parser
+    }
+}
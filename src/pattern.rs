@@ -1,7 +1,8 @@
 use regex::{Captures, Regex};
 use std::iter::Iterator;
 
-#[derive(Debug)]
+/// A regex along with how its matches bound the title.
+#[derive(Clone, Debug)]
 pub struct Pattern {
     regex: Regex,
     before_title: bool,
@@ -25,6 +26,11 @@ macro_rules! regex {
 }
 
 impl Pattern {
+    /// - `before_title`: the match precedes the title, which starts after it.
+    ///   Otherwise the title ends where the match starts.
+    /// - `capture_last`: use the last match instead of the first one.
+    /// - `no_numbers_surrounding`: ignore matches directly preceded or
+    ///   followed by a digit.
     pub fn new(
         regex: Regex,
         before_title: bool,
@@ -39,7 +45,30 @@ impl Pattern {
         }
     }
     pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
-        let mut it = self.regex.captures_iter(text).filter(|cap| {
+        self.captures_where(text, |_| true)
+    }
+
+    /// Like `captures` but only considers the matches accepted by `keep`.
+    pub(crate) fn captures_where<'t>(
+        &self,
+        text: &'t str,
+        keep: impl Fn(&Captures<'t>) -> bool,
+    ) -> Option<Captures<'t>> {
+        let mut it = self.captures_iter(text).filter(keep);
+
+        if self.capture_last {
+            it.last()
+        } else {
+            it.next()
+        }
+    }
+
+    /// Every match of the pattern, in order, honoring `no_numbers_surrounding`.
+    pub fn captures_iter<'r, 't>(&'r self, text: &'t str) -> impl Iterator<Item = Captures<'t>> + 'r
+    where
+        't: 'r,
+    {
+        self.regex.captures_iter(text).filter(move |cap| {
             if self.no_numbers_surrounding {
                 let mat = cap.get(0).unwrap();
 
@@ -52,7 +81,7 @@ impl Pattern {
                     }
 
                     let prev_char = text[prev..].chars().next().unwrap();
-                    if prev_char.is_digit(10) {
+                    if prev_char.is_ascii_digit() {
                         return false;
                     }
                 }
@@ -65,7 +94,7 @@ impl Pattern {
                         next += 1;
                     }
                     let next_char = text[next..].chars().next().unwrap();
-                    if next_char.is_digit(10) {
+                    if next_char.is_ascii_digit() {
                         return false;
                     }
                 }
@@ -73,13 +102,7 @@ impl Pattern {
             } else {
                 true
             }
-        });
-
-        if self.capture_last {
-            it.last()
-        } else {
-            it.next()
-        }
+        })
     }
 
     pub fn before_title(&self) -> bool {
@@ -91,6 +114,21 @@ lazy_static! {
     pub static ref SEASON: Pattern = regex!(
         r"(?i)s?(?P<short>\d+) ?[ex]|(?:season)(?:[^\d]|$)(?P<long>\d+)|s(?P<dash>\d+) - \d+|\.s(?P<collection>\d){1,2}\."
     );
+    // Fansub style `Title - 24`, `Title - 24v2` and batches like `Title - 01-12`
+    // or `Title (01-12)`.
+    pub static ref ABSOLUTE_EPISODE: Pattern = regex!(
+        r"[ _.]-[ _.](?P<first>\d{1,4})(?:v(?P<version>\d{1,2}))?(?:[-~](?P<last>\d{1,4})(?:v\d{1,2})?)?(?:$|[ _.\[(])|[ (\[](?P<batch_first>\d{2,3}) ?[-~] ?(?P<batch_last>\d{2,3})[ )\]]"
+    );
+    pub static ref CHECKSUM: Pattern = regex!(r"[\[(](?P<crc>[0-9A-Fa-f]{8})[\])]");
+    pub static ref DISC: Pattern = regex!(r"(?i)\b(?:CD|Dis[ck])[ .\-_]?(?P<disc>\d{1,2})\b");
+    pub static ref PART: Pattern = regex!(
+        r"(?i)\b(?:Part|Pt|Vol(?:ume)?)[ .\-_]?(?P<part>\d{1,2}|(?-i:[IVX]{1,4}))(?:[ .\-_]?of[ .\-_]?(?P<total>\d{1,2}|(?-i:[IVX]{1,4})))?\b"
+    );
+    pub static ref SEASON_RANGE: Pattern = regex!(
+        r"(?i)\bS(?P<first>\d{1,2})[ .]?(?:-|to)[ .]?S(?P<last>\d{1,2})\b|\bSeasons?[ .]?(?P<long_first>\d{1,2})[ .]?(?:-|to)[ .]?(?P<long_last>\d{1,2})\b|\b(?P<list>S\d{1,2}(?:[ .]S\d{1,2})+)\b"
+    );
+    pub static ref COMPLETE_SERIES: Pattern =
+        regex!(r"(?i)\b(?:The[ .])?Complete[ .\-_]Series\b");
     pub static ref EPISODE: Pattern = regex!(
         r"(?i)(?:e|episode)[^.\d]?(?P<short>\d{1,3})|\d+x(?P<cross>\d+)|s\d+ - (?P<dash>\d+)"
     );
@@ -102,25 +140,73 @@ lazy_static! {
         r"(?:PPV\.)?[HP]DTV|(?:HD)?CAM|B[rR]Rip|TS|(?:PPV )?WEB-?(DL)?(?: DVDRip)?|H[dD]Rip|DVDRip|DVDRiP|DVDRIP|CamRip|W[EB]B[rR]ip|[Bb]lu[Rr]ay|DvDScr|hdtv"
     );
     pub static ref CODEC: Pattern = regex!(r"(?i)xvid|x264|h\.?264/?|x265|h\.?265|hevc?");
-    pub static ref AUDIO: Pattern =
-        regex!(r"MP3|DD5\.?1|Dual[\- ]Audio|LiNE|DTS|AAC(?:\.?2\.0)?|AC3(?:\.5\.1)?");
+    pub static ref AUDIO: Pattern = regex!(
+        r"(?x)
+        (?P<format>Dual[\-\ ]Audio|LiNE|(?i:
+            \bDDP|\bDD\+|\bE-?AC-?3|\bDD|\bAC-?3|\bTrueHD|\bDTS[-.\ ]?HD[-.\ ]?MA|\bDTS[-.\ ]?HD
+            |\bDTS[-.:\ ]?X\b|\bDTS|\bFLAC|\bL?PCM|\bAAC|\bMP3)|\bOpus|\bOPUS)
+        (?:[-.\ ]?(?P<atmos_first>(?i:Atmos)))?
+        (?:[-.\ ]?(?P<channels>[1-9][.\ ]?[0-2])\b)?
+        (?:[-.\ ]?(?P<atmos>(?i:Atmos)))?"
+    );
+    pub static ref DYNAMIC_RANGE: Pattern =
+        regex!(r"(?i)\bHDR10\+|\b(?:HDR10(?:Plus)?|HDR|DV|DoVi|Dolby[ .]?Vision|HLG|SDR)\b");
+    pub static ref BIT_DEPTH: Pattern =
+        regex!(r"(?i)\b(?:(?P<depth>8|10|12)[ .-]?bits?|Hi(?P<hi>10)P)\b");
+    // Short tags are only trusted right before a WEB source, `NF` or `MAX` are
+    // too likely to be part of a title otherwise.
+    pub static ref STREAMING_SERVICE: Pattern = regex!(
+        r"\b(?:(?P<service>AMZN|ATVP|DSNP|HMAX|HULU|PCOK|PMTP)\b|(?P<short>CR|iP|iT|MAX|NF)[ .](?i:WEB))"
+    );
     pub static ref GROUP: Pattern = regex!(r"(- ?([^ -]+(?:-=\{[^ -]+-?$)?))$");
     pub static ref COUNTRY: Pattern = regex!(
         r"\W[(]?(?P<country>(?:U(?:A|G|K|M|S|Y|Z)|(?:A(?:D|E|F|G|I|L|M|N|O|R|S|T|Q|U|W|X|Z))|(?:B(?:A|B|D|E|F|G|H|I|J|L|M|N|O|R|S|T|V|W|Y|Z))|(?:C(?:A|C|D|F|G|H|I|K|L|M|N|O|R|U|V|X|Y|Z))|(?:D(?:E|J|K|M|O|Z))|(?:E(C|E|G|H|R|S|T))|(?:F(?:I|J|K|M|O|R))|(?:G(?:A|B|D|E|F|G|H|I|L|M|N|P|Q|R|S|T|U|W|Y))|(?:H(?:K|M|N|R|T|U))|(?:I(D|E|Q|L|M|N|O|R|S|T))|(?:J(?:E|M|O|P))|(?:K(E|G|H|I|M|N|P|R|W|Y|Z))|(?:L(?:A|B|C|I|K|R|S|T|U|V|Y))|(?:M(?:A|C|D|E|F|G|H|K|L|M|N|O|Q|P|R|S|T|U|V|W|X|Y|Z))|(?:N(?:A|C|E|F|G|I|L|O|P|R|U|Z))|(?:OM)|(?:P(?:A|E|F|G|H|K|L|M|N|R|S|T|W|Y))|(?:QA)|(?:R(?:E|O|S|U|W))|(?:S(?:A|B|C|D|E|G|H|I|J|K|L|M|N|O|R|T|V|Y|Z))|(?:T(?:C|D|F|G|H|J|K|L|M|N|O|R|T|V|W|Z))|(?:V(?:A|C|E|G|I|N|U))|(?:W(F|S))|(?:Y(E|T))|(?:Z(?:A|M|W)))|(?:u(?:a|g|k|m|s|y|z)|(?:a(?:d|e|f|g|i|l|m|n|o|r|s|t|q|u|w|x|z))|(?:b(?:a|b|d|e|f|g|h|i|j|l|m|n|o|r|s|t|v|w|y|z))|(?:c(?:a|c|d|f|g|h|i|k|l|m|n|o|r|u|v|x|y|z))|(?:d(?:e|j|k|m|o|z))|(?:e(c|e|g|h|r|s|t))|(?:f(?:i|j|k|m|o|r))|(?:g(?:a|b|d|e|f|g|h|i|l|m|n|p|q|r|s|t|u|w|y))|(?:h(?:k|m|n|r|t|u))|(?:i(d|e|q|l|m|n|o|r|s|t))|(?:j(?:e|m|o|p))|(?:k(e|g|h|i|m|n|p|r|w|y|z))|(?:l(?:a|b|c|i|k|r|s|t|u|v|y))|(?:m(?:a|c|d|e|f|g|h|k|l|m|n|o|q|p|r|s|t|u|v|w|x|y|z))|(?:n(?:a|c|e|f|g|i|l|o|p|r|u|z))|(?:om)|(?:p(?:a|e|f|g|h|k|l|m|n|r|s|t|w|y))|(?:qa)|(?:r(?:e|o|s|u|w))|(?:s(?:a|b|c|d|e|g|h|i|j|k|l|m|n|o|r|t|v|y|z))|(?:t(?:c|d|f|g|h|j|k|l|m|n|o|r|t|v|w|z))|(?:v(?:a|c|e|g|i|n|u))|(?:w(f|s))|(?:y(e|t))|(?:z(?:a|m|w))))[)]?\.S\d"
     );
-    pub static ref REGION: Pattern = regex!(r"R\d");
+    pub static ref REGION: Pattern = regex!(r"\bR\d\b");
+    // `DC` alone is too short to trust anywhere but right after the year.
+    pub static ref EDITION: Pattern = regex!(
+        r"(?i)\b(?P<edition>Director'?s[ .\-_]?Cut|Theatrical(?:[ .\-_](?:Cut|Edition))?|(?:The[ .\-_])?Final[ .\-_]Cut|Remaster(?:ed)?|Criterion(?:[ .\-_]Collection)?|IMAX(?:[ .\-_]Edition)?|Special[ .\-_]Edition|(?:\d{1,3}(?:st|nd|rd|th)[ .\-_])?Anniversary(?:[ .\-_]Edition)?|Uncut|Open[ .\-_]?Matte)\b|\b(?:19|20)\d\d[ .\-_)\]]+(?P<dc>(?-i:DC))\b"
+    );
     pub static ref EXTENDED: Pattern = regex!(r"EXTENDED");
     pub static ref HARDCODED: Pattern = regex!(r"HC");
     pub static ref PROPER: Pattern = regex!(r"PROPER");
     pub static ref REPACK: Pattern = regex!(r"REPACK");
-    pub static ref CONTAINER: Pattern = regex!(r"MKV|AVI");
+    pub static ref RELEASE_FLAGS: Pattern = regex!(
+        r"\b(?:[Ii]NTERNAL|LIMITED|REAL|RERIP|DUBBED|SUBBED|READNFO|NFOFIX|DIRFIX|SYNCFIX|CONVERT)\b"
+    );
+    pub static ref CONTAINER: Pattern = regex!(r"\b(?:MKV|AVI)\b");
     pub static ref WIDESCREEN: Pattern = regex!(r"WS");
     pub static ref THREE_D: Pattern = regex!(r"3D");
     pub static ref UNRATED: Pattern = regex!(r"UNRATED");
-    pub static ref LANGUAGE: Pattern =
-        regex!(r"\b(rus\.eng|US|MULT[Ii]|[mM]ulti|FRENCH|[fF]rench|TRUEFRENCH|VFF)\b");
-    pub static ref GARBAGE: Pattern = regex!(r"1400Mb|3rd Nov|((Rip)) ");
+    // Three letter codes are only matched in capitals, or when joined as in
+    // `rus.eng`.
+    pub static ref LANGUAGE: Pattern = regex!(&format!(
+        r"\b(?:(?P<composite>(?i:{codes})(?:[.\-](?i:{codes}))+)|(?P<code>{codes})|(?P<name>(?i:{names}))|US|MULT[Ii]|[mM]ulti|TRUEFRENCH|VF[FQI])\b",
+        codes = "ENG|FRE|FRA|GER|DEU|ESP|SPA|ITA|POR|RUS|JAP|JPN|KOR|CHI|ZHO|HIN|ARA|DUT|NLD|POL|TUR|SWE|NOR|DAN|FIN|CZE|HUN|GRE|HEB|THA|VIE|UKR|TAM|TEL",
+        names = "English|French|German|Spanish|Castellano|Latino|Italian|Portuguese|Russian|Japanese|Korean|Chinese|Mandarin|Cantonese|Hindi|Arabic|Dutch|Polish|Turkish|Swedish|Norwegian|Danish|Finnish|Czech|Hungarian|Greek|Hebrew|Thai|Vietnamese|Ukrainian|Tamil|Telugu",
+    ));
+    pub static ref SUBTITLES: Pattern = regex!(
+        r"(?i)\b(?:(?P<english>E-?Subs?)|(?P<multi>Multi[ .\-]?Subs?)|(?P<lang>NL|EN|ENG|FR|FRE|DE|GER|ES|ESP|SPA|IT|ITA|PT|POR|RU|RUS|PL|POL|SWE|NOR|DAN|FIN|KOR|JAP|CHI|HIN|ARA|TUR)[ .\-]?Subs?|VOST(?P<vost>FR)?|SUB(?P<sub>FRENCH))\b"
+    );
+    // Matched against whole path components, see `Metadata::from_path`.
+    pub static ref SEASON_FOLDER: Pattern = regex!(
+        r"(?i)^(?:(?:Season|Series|Saison|Staffel)[ ._\-]*|S)(?P<season>\d{1,2})$|^(?P<specials>Specials?)$"
+    );
+    pub static ref LEADING_EPISODE: Pattern = regex!(r"^(?P<episode>\d{1,3})\b[ ._\-]*");
+    // Files of a multi-file torrent that are not part of the release itself.
+    pub static ref SAMPLE: Pattern = regex!(r"(?i)(?:^|[^a-z])samples?(?:[^a-z]|$)");
+    pub static ref EXTRAS: Pattern = regex!(
+        r"(?i)^(?:Extras?|Bonus|Featurettes?|Behind[ ._\-]the[ ._\-]Scenes|Deleted[ ._\-]Scenes|Interviews|Trailers?|Shorts)$|[ ._\-](?:trailer|featurette)$"
+    );
+    // A decimal size has at most three integer digits, so the year in
+    // `Movie.2019.750MB` is not read as `2019.750MB`.
+    pub static ref SIZE: Pattern =
+        regex!(r"(?i)\b(?P<value>\d{1,3}[.,]\d+|\d+) ?(?P<unit>[KMGT]i?B)\b");
+    pub static ref GARBAGE: Pattern = regex!(r"3rd Nov|((Rip)) ");
     pub static ref IMDB: Pattern = regex!(r"tt\d{7}");
+    pub static ref AIR_DATE: Pattern = regex!(
+        r"\b(?:(?P<year>(?:19|20)\d\d)[-. /](?P<month>\d{1,2})[-. /](?P<day>\d{1,2})|(?P<first>\d{1,2})[-. /](?P<second>\d{1,2})[-. /](?P<trailing_year>(?:19|20)\d\d))\b"
+    );
     pub static ref YEAR: Pattern = regex!(r"(?P<year>(1[89]|20)\d\d)", false, true, true);
     pub static ref WEBSITE: Pattern = regex!(r"^(\[ ?([^\]]+?) ?\]) ?", true, false, false);
 }
@@ -19,8 +19,8 @@ fn names() {
     assert_eq!(m.episode(), None);
     assert_eq!(m.year(), Some(2018));
     assert_eq!(m.title(), "Pacific Rim 2 Uprising");
-    assert_eq!(m.resolution(), Some("1080p"));
-    assert_eq!(m.quality(), Some("HDRip"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
+    assert_eq!(m.quality_raw(), Some("HDRip"));
     assert_eq!(m.extension(), None);
 
     let m = Metadata::from("Blade.Runner.2049.2017.HDRip").unwrap();
@@ -28,7 +28,7 @@ fn names() {
     assert_eq!(m.episode(), None);
     assert_eq!(m.year(), Some(2017));
     assert_eq!(m.title(), "Blade Runner 2049");
-    assert_eq!(m.quality(), Some("HDRip"));
+    assert_eq!(m.quality_raw(), Some("HDRip"));
     assert_eq!(m.extension(), None);
 
     let m = Metadata::from("Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS")
@@ -36,7 +36,7 @@ fn names() {
     assert_eq!(m.season(), Some(1));
     assert_eq!(m.episode(), Some(3));
     assert_eq!(m.year(), None);
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.title(), "Euphoria");
     assert_eq!(m.extension(), None);
     assert_eq!(m.country(), Some("US"));
@@ -45,7 +45,7 @@ fn names() {
     assert_eq!(m.season(), Some(1));
     assert_eq!(m.episode(), Some(10));
     assert_eq!(m.year(), None);
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.title(), "narcos");
     assert_eq!(m.extension(), None);
 
@@ -53,7 +53,7 @@ fn names() {
     assert_eq!(m.season(), Some(1));
     assert_eq!(m.episode(), Some(11));
     assert_eq!(m.year(), None);
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.title(), "Rome");
     assert_eq!(m.extension(), None);
 
@@ -61,7 +61,7 @@ fn names() {
     assert_eq!(m.season(), Some(1));
     assert_eq!(m.episode(), Some(9));
     assert_eq!(m.year(), None);
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.title(), "the expanse");
     assert_eq!(m.extension(), None);
 
@@ -69,7 +69,7 @@ fn names() {
     assert_eq!(m.season(), Some(2));
     assert_eq!(m.episode(), Some(30));
     assert_eq!(m.year(), None);
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.title(), "Attack on Titan");
     assert_eq!(m.extension(), None);
 
@@ -78,8 +78,8 @@ fn names() {
     assert_eq!(m.episode(), Some(3));
     assert_eq!(m.year(), None);
     assert_eq!(m.title(), "The Walking Dead");
-    assert_eq!(m.resolution(), Some("720p"));
-    assert_eq!(m.codec(), Some("x264"));
+    assert_eq!(m.resolution_raw(), Some("720p"));
+    assert_eq!(m.codec_raw(), Some("x264"));
     assert_eq!(m.group(), Some("ASAP[ettv]"));
     assert_eq!(m.extension(), None);
 
@@ -88,9 +88,9 @@ fn names() {
     assert_eq!(m.episode(), None);
     assert_eq!(m.year(), Some(2014));
     assert_eq!(m.title(), "Hercules");
-    assert_eq!(m.resolution(), Some("1080p"));
-    assert_eq!(m.quality(), Some("BrRip"));
-    assert_eq!(m.codec(), Some("H264"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
+    assert_eq!(m.quality_raw(), Some("BrRip"));
+    assert_eq!(m.codec_raw(), Some("H264"));
     assert_eq!(m.group(), Some("YIFY"));
     assert_eq!(m.extension(), None);
 
@@ -99,8 +99,8 @@ fn names() {
     assert_eq!(m.episode(), None);
     assert_eq!(m.year(), Some(2014));
     assert_eq!(m.title(), "Dawn of the Planet of the Apes");
-    assert_eq!(m.quality(), Some("HDRip"));
-    assert_eq!(m.codec(), Some("XViD"));
+    assert_eq!(m.quality_raw(), Some("HDRip"));
+    assert_eq!(m.codec_raw(), Some("XViD"));
     assert_eq!(m.group(), Some("EVO"));
     assert_eq!(m.extension(), None);
 
@@ -124,9 +124,9 @@ fn names() {
     assert_eq!(m.year(), Some(2014));
     assert_eq!(m.title(), "Hercules");
     assert!(m.extended());
-    assert_eq!(m.audio(), Some("DD5.1"));
-    assert_eq!(m.resolution(), Some("1080p"));
-    assert_eq!(m.codec(), Some("H264"));
+    assert_eq!(m.audio_raw(), Some("DD5.1"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
+    assert_eq!(m.codec_raw(), Some("H264"));
     assert_eq!(m.group(), Some("RARBG"));
     assert_eq!(m.extension(), None);
 
@@ -319,8 +319,8 @@ fn names() {
     .unwrap();
     assert_eq!(m.season(), None);
     assert_eq!(m.episode(), None);
-    assert_eq!(m.resolution(), Some("1080p"));
-    assert_eq!(m.quality(), Some("WEB-DL"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
+    assert_eq!(m.quality_raw(), Some("WEB-DL"));
     assert_eq!(m.title(), "To All The Boys Always And Forever");
     assert_eq!(m.year(), Some(2021));
     assert_eq!(m.extension(), None);
@@ -330,7 +330,7 @@ fn names() {
     assert_eq!(m.season(), Some(3));
     assert_eq!(m.episode(), Some(1));
     assert_eq!(m.title(), "The EXPANSE");
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.extension(), None);
 
     let m = Metadata::from("[Judas] Re Zero 2020 - S01E01").unwrap();
@@ -344,21 +344,21 @@ fn names() {
     assert_eq!(m.season(), Some(4));
     assert_eq!(m.episode(), Some(3));
     assert_eq!(m.title(), "Fargo");
-    assert_eq!(m.quality(), Some("WEB"));
+    assert_eq!(m.quality_raw(), Some("WEB"));
     assert_eq!(m.extension(), None);
 
     let m = Metadata::from("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]").unwrap();
     assert_eq!(m.season(), Some(2));
     assert_eq!(m.episode(), Some(7));
     assert_eq!(m.title(), "Dr. Stone");
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.extension(), None);
 
     let m = Metadata::from("[SubsPlease] Fumetsu no Anata e S2 - 01 (1080p) [1D65E30D]").unwrap();
     assert_eq!(m.season(), Some(2));
     assert_eq!(m.episode(), Some(1));
     assert_eq!(m.title(), "Fumetsu no Anata e");
-    assert_eq!(m.resolution(), Some("1080p"));
+    assert_eq!(m.resolution_raw(), Some("1080p"));
     assert_eq!(m.extension(), None);
 
     let m = Metadata::from(
@@ -380,8 +380,13 @@ fn names() {
     assert_eq!(m.title(), "Yes Day");
     assert_eq!(m.extension(), None);
 
-    // FIXME
-    Metadata::from("[Rip Time] Mushoku Tensei_ Jobless Reincarnation - 12 [1080p]").unwrap_err();
+    let m =
+        Metadata::from("[Rip Time] Mushoku Tensei_ Jobless Reincarnation - 12 [1080p]").unwrap();
+    assert_eq!(m.season(), None);
+    assert_eq!(m.episode(), Some(12));
+    assert_eq!(m.title(), "Mushoku Tensei Jobless Reincarnation");
+    assert_eq!(m.group(), Some("Rip Time"));
+    assert_eq!(m.extension(), None);
 
     let m = Metadata::from(
         "Pokémon the Movie - Black - Victini and Reshiram (2011) [x265 Bluray-1080p] [tt1961324]",
@@ -437,6 +442,7 @@ mod extensions {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod special {
     use crate::metadata::Metadata;
 
@@ -459,6 +465,7 @@ mod special {
 }
 
 #[cfg(test)]
+#[allow(clippy::len_zero, clippy::explicit_counter_loop)]
 mod multi_episodes {
     use super::*;
 
@@ -760,20 +767,20 @@ mod resolution {
         let m =
             Metadata::from("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool")
                 .unwrap();
-        assert_eq!(m.resolution(), Some("720p"));
+        assert_eq!(m.resolution_raw(), Some("720p"));
     }
 
     #[test]
     fn resolution_2160p() {
         let m =
             Metadata::from("Black Widow 2021 MULTi VFF 2160p UHD Bluray HDR x265-DUSTiN").unwrap();
-        assert_eq!(m.resolution(), Some("2160p"));
+        assert_eq!(m.resolution_raw(), Some("2160p"));
     }
 
     #[test]
     fn resolution_string_end() {
         let m = Metadata::from("Black Widow 2021 FRENCH BluRay 1080p").unwrap();
-        assert_eq!(m.resolution(), Some("1080p"));
+        assert_eq!(m.resolution_raw(), Some("1080p"));
     }
 
     #[test]
@@ -782,7 +789,7 @@ mod resolution {
             "BLACK WIDOW 2021 VFF VO TRUEHD ATMOS 2160P UHD BLURAY REMUX HEVC HDR10-Obi",
         )
         .unwrap();
-        assert_eq!(m.resolution(), Some("2160P"));
+        assert_eq!(m.resolution_raw(), Some("2160P"));
     }
 
     #[test]
@@ -790,7 +797,7 @@ mod resolution {
         let m =
             Metadata::from("Kaamelott Integrale FRENCH [1080p] BDRIP HEVC-H265 10bits - Themouche")
                 .unwrap();
-        assert_eq!(m.resolution(), Some("1080p"));
+        assert_eq!(m.resolution_raw(), Some("1080p"));
     }
 }
 
@@ -800,3 +807,1741 @@ fn unicode() {
     Metadata::from("é2000").unwrap();
     Metadata::from("é2000é").unwrap();
 }
+
+#[cfg(test)]
+mod typed {
+    use crate::metadata::Metadata;
+    use crate::types::{AudioFormat, Quality, Resolution, VideoCodec};
+
+    #[test]
+    fn quality() {
+        let m = Metadata::from("Blade.Runner.2049.2017.HDRip").unwrap();
+        assert_eq!(m.quality(), Some(&Quality::HdRip));
+        assert_eq!(m.quality_raw(), Some("HDRip"));
+
+        let m = Metadata::from("Teenage Mutant Ninja Turtles (HdRip / 2014)").unwrap();
+        assert_eq!(m.quality(), Some(&Quality::HdRip));
+        assert_eq!(m.quality_raw(), Some("HdRip"));
+
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.quality(), Some(&Quality::BluRay));
+
+        let m = Metadata::from("The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]").unwrap();
+        assert_eq!(m.quality(), Some(&Quality::HdTv));
+
+        let m = Metadata::from("Hercules (2014) WEBDL DVDRip XviD-MAX").unwrap();
+        assert_eq!(m.quality(), Some(&Quality::WebDl));
+    }
+
+    #[test]
+    fn codec() {
+        let m = Metadata::from("The.Walking.Dead.S05E03.1080p.WEB-DL.DD5.1.H.264-Cyphanix[rartv]")
+            .unwrap();
+        assert_eq!(m.codec(), Some(&VideoCodec::H264));
+        assert_eq!(m.codec_raw(), Some("H.264"));
+
+        let m = Metadata::from("Dawn.of.the.Planet.of.the.Apes.2014.HDRip.XViD-EVO").unwrap();
+        assert_eq!(m.codec(), Some(&VideoCodec::Xvid));
+
+        let m = Metadata::from("Rome.S01E11.The.Spoils.BluRay.10Bit.1080p.Dts.H265-d3g").unwrap();
+        assert_eq!(m.codec(), Some(&VideoCodec::H265));
+    }
+
+    #[test]
+    fn resolution() {
+        let m = Metadata::from(
+            "BLACK WIDOW 2021 VFF VO TRUEHD ATMOS 2160P UHD BLURAY REMUX HEVC HDR10-Obi",
+        )
+        .unwrap();
+        assert_eq!(m.resolution(), Some(&Resolution::P2160));
+        assert_eq!(m.resolution_raw(), Some("2160P"));
+
+        let m = Metadata::from("Movie.2014.900p.WEB-DL").unwrap();
+        assert_eq!(m.resolution(), Some(&Resolution::Other("900p".to_string())));
+    }
+
+    #[test]
+    fn audio() {
+        let m = Metadata::from("Hercules.2014.EXTENDED.1080p.WEB-DL.DD5.1.H264-RARBG").unwrap();
+        assert_eq!(
+            m.audio().map(|a| &a.format),
+            Some(&AudioFormat::DolbyDigital)
+        );
+        assert_eq!(m.audio_raw(), Some("DD5.1"));
+
+        let m = Metadata::from("Annabelle.2014.HC.HDRip.XViD.AC3-juggs[ETRG]").unwrap();
+        assert_eq!(
+            m.audio().map(|a| &a.format),
+            Some(&AudioFormat::DolbyDigital)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Quality::WebDl.to_string(), "WEB-DL");
+        assert_eq!(VideoCodec::from("h.264/").to_string(), "H.264");
+        assert_eq!(Resolution::from("2160P").to_string(), "2160p");
+        assert_eq!(AudioFormat::Other("WMA".to_string()).to_string(), "WMA");
+    }
+}
+
+#[cfg(test)]
+mod spans {
+    use crate::metadata::Metadata;
+    use crate::types::Span;
+
+    #[test]
+    fn spans_slice_name() {
+        let name = "The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]";
+        let m = Metadata::from(name).unwrap();
+        let slice = |field| {
+            let span = m.span(field).unwrap();
+            &name[span.start..span.end]
+        };
+        assert_eq!(slice("title"), "The Walking Dead");
+        assert_eq!(slice("season"), "S05E");
+        assert_eq!(slice("episode"), "E03");
+        assert_eq!(slice("resolution"), "720p");
+        assert_eq!(slice("quality"), "HDTV");
+        assert_eq!(slice("codec"), "x264");
+        assert_eq!(slice("group"), "-ASAP[ettv]");
+        assert_eq!(m.span("year"), None);
+    }
+
+    #[test]
+    fn span_title_after_website() {
+        let name = "[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.span("website"), Some(Span::new(0, 19)));
+        assert_eq!(m.span("title"), Some(Span::new(21, 36)));
+        assert_eq!(m.span("year"), Some(Span::new(38, 42)));
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use crate::error::{ParseError, ParseWarning};
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn missing_title_carries_metadata() {
+        let err = Metadata::from("S01E01.720p.HDTV").unwrap_err();
+        assert!(matches!(err, ParseError::MissingTitle(_)));
+        let m = err.metadata();
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), Some(1));
+        assert_eq!(m.resolution_raw(), Some("720p"));
+        assert_eq!(err.into_metadata().title(), "");
+    }
+
+    #[test]
+    fn empty_name() {
+        let err = Metadata::from("").unwrap_err();
+        assert_eq!(err.to_string(), "Couldn't find a title.");
+    }
+
+    #[test]
+    fn lenient() {
+        let m = Metadata::from_lenient("1080p.BluRay.x264-GROUP");
+        assert_eq!(m.title(), "");
+        assert_eq!(m.warnings(), &[ParseWarning::MissingTitle]);
+        assert_eq!(m.resolution_raw(), Some("1080p"));
+        assert_eq!(m.group(), Some("GROUP"));
+
+        let m = Metadata::from_lenient("narcos.s01e10.1080p.bluray.x264-rovers");
+        assert_eq!(m.title(), "narcos");
+        assert!(m.warnings().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod parser {
+    use crate::parser::{Field, LeadingBracket, Parser};
+
+    #[test]
+    fn default_matches_metadata() {
+        let name = "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS";
+        assert_eq!(
+            Parser::new().parse(name).unwrap(),
+            crate::Metadata::from(name).unwrap()
+        );
+    }
+
+    #[test]
+    fn disable_country() {
+        let parser = Parser::builder().disable(Field::Country).build();
+        let m = parser.parse("Life.on.Mars.UK.S01E01.avi").unwrap();
+        assert_eq!(m.country(), None);
+        assert_eq!(m.title(), "Life on Mars UK");
+        assert_eq!(m.span("country"), None);
+        assert!(!parser.is_enabled(Field::Country));
+        assert!(parser.is_enabled(Field::Group));
+    }
+
+    #[test]
+    fn disable_group() {
+        let parser = Parser::builder().disable(Field::Group).build();
+        let m = parser
+            .parse("Hercules (2014) 1080p BrRip H264 - YIFY")
+            .unwrap();
+        assert_eq!(m.group(), None);
+        assert_eq!(m.title(), "Hercules");
+    }
+
+    #[test]
+    fn enable_after_disable() {
+        let parser = Parser::builder()
+            .disable(Field::Year)
+            .enable(Field::Year)
+            .build();
+        let m = parser.parse("Blade.Runner.2049.2017.HDRip").unwrap();
+        assert_eq!(m.year(), Some(2017));
+    }
+
+    #[test]
+    fn leading_bracket_as_group() {
+        let parser = Parser::builder()
+            .leading_bracket(LeadingBracket::Group)
+            .build();
+        let m = parser.parse("[Judas] Re Zero 2020 - S01E01").unwrap();
+        assert_eq!(m.group(), Some("Judas"));
+        assert_eq!(m.title(), "Re Zero");
+        assert_eq!(m.span("website"), None);
+
+        let m = Parser::new()
+            .parse("[Judas] Re Zero 2020 - S01E01")
+            .unwrap();
+        assert_ne!(m.group(), Some("Judas"));
+        assert!(m.span("website").is_some());
+    }
+}
+
+#[cfg(test)]
+mod custom_patterns {
+    use crate::parser::Parser;
+    use crate::pattern::Pattern;
+    use regex::Regex;
+
+    #[test]
+    fn custom_after_title() {
+        let parser = Parser::builder()
+            .custom_pattern(
+                "house_tag",
+                Pattern::new(Regex::new(r"\bINT-(\w+)").unwrap(), false, false, false),
+                |caps| caps.get(1).map(|m| m.as_str().to_lowercase()),
+            )
+            .build();
+        let name = "Some.Movie.INT-GOLD.1080p.BluRay.x264-GROUP";
+        let m = parser.parse(name).unwrap();
+        assert_eq!(m.title(), "Some Movie");
+        assert_eq!(m.extra().get("house_tag").map(String::as_str), Some("gold"));
+        let span = m.span("house_tag").unwrap();
+        assert_eq!(&name[span.start..span.end], "INT-GOLD");
+    }
+
+    #[test]
+    fn custom_before_title() {
+        let parser = Parser::builder()
+            .custom_pattern(
+                "tracker",
+                Pattern::new(Regex::new(r"^\{(\w+)\}").unwrap(), true, false, false),
+                |caps| caps.get(1).map(|m| m.as_str().to_string()),
+            )
+            .build();
+        let m = parser.parse("{XYZ}Hercules.2014.720p").unwrap();
+        assert_eq!(m.title(), "Hercules");
+        assert_eq!(m.extra().get("tracker").map(String::as_str), Some("XYZ"));
+    }
+
+    #[test]
+    fn custom_no_match() {
+        let parser = Parser::builder()
+            .custom_pattern(
+                "house_tag",
+                Pattern::new(Regex::new(r"\bINT-(\w+)").unwrap(), false, false, false),
+                |caps| caps.get(1).map(|m| m.as_str().to_string()),
+            )
+            .build();
+        let m = parser.parse("Hercules.2014.720p").unwrap();
+        assert!(m.extra().is_empty());
+        assert_eq!(m.title(), "Hercules");
+    }
+
+    #[test]
+    #[should_panic(expected = "built-in field")]
+    fn custom_built_in_name() {
+        Parser::builder().custom_pattern(
+            "edition",
+            Pattern::new(Regex::new(r"\bGOLD\b").unwrap(), false, false, false),
+            |caps| caps.get(0).map(|m| m.as_str().to_string()),
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde {
+    use crate::metadata::Metadata;
+    use crate::types::{Quality, Resolution};
+
+    #[test]
+    fn round_trip() {
+        for name in [
+            "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS",
+            "the.expanse.s01e09e11.1080p.bluray.x264-rovers",
+            "[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool",
+            "A Shaun the Sheep Movie - Farmageddon (2019) [h265 Remux-1080p] [tt6193408]",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            let json = serde_json::to_string(&m).unwrap();
+            assert_eq!(serde_json::from_str::<Metadata>(&json).unwrap(), m);
+        }
+        let m = Metadata::from_lenient("S01E01.720p.HDTV");
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(serde_json::from_str::<Metadata>(&json).unwrap(), m);
+    }
+
+    #[test]
+    fn field_names() {
+        let m = Metadata::from("Hercules (2014) 1080p BrRip H264 - YIFY [tt1267297]").unwrap();
+        let json = serde_json::to_value(&m).unwrap();
+        assert_eq!(json["title"], "Hercules");
+        assert_eq!(json["year"], 2014);
+        assert_eq!(json["resolution"], "1080p");
+        assert_eq!(json["quality"], "BRRip");
+        assert_eq!(json["quality_raw"], "BrRip");
+        assert_eq!(json["codec"], "H.264");
+        assert_eq!(json["imdb_tag"], "tt1267297");
+        assert_eq!(json["spans"]["year"]["start"], 10);
+
+        let m = Metadata::from("Big Fish 4.37GB").unwrap();
+        let json = serde_json::to_value(&m).unwrap();
+        assert_eq!(json["size_bytes"], 4_370_000_000u64);
+    }
+
+    #[test]
+    fn enum_values() {
+        let m = Metadata::from("Show.S01E01.2160p.AMZN.WEB-DL.DV.HDR.DDP5.1.x265-GRP").unwrap();
+        let json = serde_json::to_value(&m).unwrap();
+        assert_eq!(
+            json["dynamic_range"],
+            serde_json::json!(["dolby_vision", "hdr"])
+        );
+        assert_eq!(json["streaming_service"], "amazon");
+        assert_eq!(json["quality"], "WEB-DL");
+        assert_eq!(serde_json::from_value::<Metadata>(json).unwrap(), m);
+    }
+
+    #[test]
+    fn language_codes() {
+        let m = Metadata::from("Movie.2019.FRENCH.1080p.BluRay.x264.NLSubs-GRP").unwrap();
+        let json = serde_json::to_value(&m).unwrap();
+        assert_eq!(json["languages"], serde_json::json!(["fr"]));
+        assert_eq!(json["subtitles"][0]["language"], "nl");
+        assert_eq!(serde_json::from_value::<Metadata>(json).unwrap(), m);
+
+        let json = r#"{"title": "Movie", "languages": ["klingon"]}"#;
+        assert!(serde_json::from_str::<Metadata>(json).is_err());
+    }
+
+    #[test]
+    fn missing_fields_default() {
+        let m: Metadata =
+            serde_json::from_str(r#"{"title": "Hercules", "quality": "bluray"}"#).unwrap();
+        assert_eq!(m.title(), "Hercules");
+        assert_eq!(m.quality(), Some(&Quality::BluRay));
+        assert_eq!(m.resolution(), None::<&Resolution>);
+        assert!(m.episodes().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod dropped_fields {
+    use crate::metadata::Metadata;
+    use crate::parser::{LeadingBracket, Parser};
+
+    #[test]
+    fn website() {
+        let m =
+            Metadata::from("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool")
+                .unwrap();
+        assert_eq!(m.website(), Some("www.UsaBit.com"));
+
+        let m = Metadata::from(
+            "[TorrentCounter.to].Pacific.Rim.2.Uprising.2018.1080p.HC.HDRip.x264.[2GB]",
+        )
+        .unwrap();
+        assert_eq!(m.website(), Some("TorrentCounter.to"));
+
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.website(), None);
+    }
+
+    #[test]
+    fn website_as_group() {
+        let parser = Parser::builder()
+            .leading_bracket(LeadingBracket::Group)
+            .build();
+        let m = parser
+            .parse("[SubsPlease] Fumetsu no Anata e S2 - 01 (1080p) [1D65E30D]")
+            .unwrap();
+        assert_eq!(m.website(), None);
+        assert_eq!(m.group(), Some("SubsPlease"));
+    }
+
+    #[test]
+    fn trailing_group_wins() {
+        let name = "[ettv] Show.S01E01.720p.HDTV.x264-LOL";
+        let m = Parser::builder()
+            .leading_bracket(LeadingBracket::Group)
+            .build()
+            .parse(name)
+            .unwrap();
+        assert_eq!(m.group(), Some("LOL"));
+        let span = m.span("group").unwrap();
+        assert_eq!(&name[span.start..span.end], "-LOL");
+
+        let m = Metadata::from("[RARBG] Movie.2019.1080p.BluRay.x264-SPARKS").unwrap();
+        assert_eq!(m.group(), Some("SPARKS"));
+        assert_eq!(m.website(), Some("RARBG"));
+    }
+
+    #[test]
+    fn region() {
+        let m = Metadata::from("Brave.2012.R5.DVDRip.XViD.LiNE-UNiQUE").unwrap();
+        assert_eq!(m.region(), Some("R5"));
+
+        let m = Metadata::from("Guardians Of The Galaxy 2014 R6 720p HDCAM x264-JYK").unwrap();
+        assert_eq!(m.region(), Some("R6"));
+    }
+
+    #[test]
+    fn region_inside_words() {
+        let m = Metadata::from("Dune.2021.2160p.WEB-DL.HDR10.DDP5.1.x265").unwrap();
+        assert_eq!(m.region(), None);
+
+        let m = Metadata::from("Movie.2019.720p.BluRay.x264-R2D2").unwrap();
+        assert_eq!(m.region(), None);
+    }
+
+    #[test]
+    fn container() {
+        let m = Metadata::from("Some.Movie.2014.MKV.1080p").unwrap();
+        assert_eq!(m.container(), Some("MKV"));
+
+        let m = Metadata::from("Lucy.2014.HC.HDRip.XViD-juggs[ETRG]").unwrap();
+        assert_eq!(m.container(), None);
+    }
+
+    #[test]
+    fn container_inside_words() {
+        let m = Metadata::from("SAVING.PRIVATE.RYAN.1998.720p.BluRay.x264").unwrap();
+        assert_eq!(m.container(), None);
+        assert_eq!(m.title(), "SAVING PRIVATE RYAN");
+
+        let m = Metadata::from("THE.AVIATOR.2004.1080p.BluRay.x264").unwrap();
+        assert_eq!(m.container(), None);
+        assert_eq!(m.title(), "THE AVIATOR");
+    }
+}
+
+#[cfg(test)]
+mod dynamic_range {
+    use crate::metadata::Metadata;
+    use crate::types::DynamicRange;
+
+    #[test]
+    fn hdr() {
+        let m = Metadata::from(
+            "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD",
+        )
+        .unwrap();
+        assert_eq!(m.dynamic_range(), &[DynamicRange::Hdr]);
+        assert_eq!(m.title(), "To All The Boys Always And Forever");
+    }
+
+    #[test]
+    fn hdr10() {
+        let m = Metadata::from(
+            "BLACK WIDOW 2021 VFF VO TRUEHD ATMOS 2160P UHD BLURAY REMUX HEVC HDR10-Obi",
+        )
+        .unwrap();
+        assert_eq!(m.dynamic_range(), &[DynamicRange::Hdr10]);
+    }
+
+    #[test]
+    fn hdr10_plus() {
+        let m =
+            Metadata::from("Dune.2021.2160p.MAX.WEB-DL.DDP5.1.Atmos.HDR10+.HEVC-GROUP").unwrap();
+        assert_eq!(m.dynamic_range(), &[DynamicRange::Hdr10Plus]);
+        assert_eq!(m.title(), "Dune");
+    }
+
+    #[test]
+    fn dolby_vision_combination() {
+        let name = "Dune.2021.2160p.WEB-DL.DDP5.1.Atmos.DV.HDR10.HEVC-GROUP";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(
+            m.dynamic_range(),
+            &[DynamicRange::DolbyVision, DynamicRange::Hdr10]
+        );
+        let span = m.span("dynamic_range").unwrap();
+        assert_eq!(&name[span.start..span.end], "DV.HDR10");
+
+        let m = Metadata::from("The.Mandalorian.S02E01.2160p.DoVi.HDR.HLG.x265").unwrap();
+        assert_eq!(
+            m.dynamic_range(),
+            &[
+                DynamicRange::DolbyVision,
+                DynamicRange::Hdr,
+                DynamicRange::Hlg
+            ]
+        );
+    }
+
+    #[test]
+    fn dynamic_range_bounds_title() {
+        let m = Metadata::from("Some Movie HDR 2160p").unwrap();
+        assert_eq!(m.title(), "Some Movie");
+        assert_eq!(m.dynamic_range(), &[DynamicRange::Hdr]);
+
+        let m = Metadata::from("Some.Movie.2019.SDR.1080p.BluRay").unwrap();
+        assert_eq!(m.dynamic_range(), &[DynamicRange::Sdr]);
+    }
+
+    #[test]
+    fn no_dynamic_range() {
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert!(m.dynamic_range().is_empty());
+        let m = Metadata::from("Brave.2012.R5.DVDRip.XViD.LiNE-UNiQUE").unwrap();
+        assert!(m.dynamic_range().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod streaming_service {
+    use crate::metadata::Metadata;
+    use crate::types::StreamingService;
+
+    #[test]
+    fn amazon() {
+        let name = "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Amazon));
+        let span = m.span("streaming_service").unwrap();
+        assert_eq!(&name[span.start..span.end], "AMZN");
+
+        let m = Metadata::from("The EXPANSE - S03 E01 - Fight or Flight (1080p - AMZN Web-DL)")
+            .unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Amazon));
+    }
+
+    #[test]
+    fn short_tags_before_web() {
+        let name =
+            "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Netflix));
+        let span = m.span("streaming_service").unwrap();
+        assert_eq!(&name[span.start..span.end], "NF");
+
+        let m =
+            Metadata::from("Dune.2021.2160p.MAX.WEB-DL.DDP5.1.Atmos.HDR10+.HEVC-GROUP").unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Max));
+
+        let m = Metadata::from("Ted.Lasso.S01E01.Pilot.1080p.iT.WEB-DL.DD5.1.H.264-GROUP").unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::ITunes));
+    }
+
+    #[test]
+    fn long_tags() {
+        for (tag, service) in [
+            ("DSNP", StreamingService::DisneyPlus),
+            ("HMAX", StreamingService::HboMax),
+            ("ATVP", StreamingService::AppleTvPlus),
+            ("HULU", StreamingService::Hulu),
+            ("PCOK", StreamingService::Peacock),
+            ("PMTP", StreamingService::ParamountPlus),
+        ] {
+            let m =
+                Metadata::from(&format!("Show.S01E01.1080p.{}.WEBRip.x264-GROUP", tag)).unwrap();
+            assert_eq!(m.streaming_service(), Some(service));
+            assert_eq!(service.tag(), tag);
+            assert_eq!(m.title(), "Show");
+        }
+    }
+
+    #[test]
+    fn short_tags_in_title() {
+        let m = Metadata::from("Mad.Max.Fury.Road.2015.1080p.BluRay.x264-SPARKS").unwrap();
+        assert_eq!(m.streaming_service(), None);
+        assert_eq!(m.title(), "Mad Max Fury Road");
+    }
+}
+
+#[cfg(test)]
+mod audio {
+    use crate::metadata::Metadata;
+    use crate::types::{Audio, AudioFormat};
+
+    fn audio(name: &str) -> Audio {
+        Metadata::from(name).unwrap().audio().cloned().unwrap()
+    }
+
+    #[test]
+    fn codec_words_in_titles() {
+        for (name, title) in [
+            (
+                "Mr.Hollands.Opus.1995.1080p.BluRay.x264",
+                "Mr Hollands Opus",
+            ),
+            ("Opus.2025.1080p.WEB-DL", "Opus"),
+            ("The.DD.Show.S01E01", "The DD Show"),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.title(), title, "{}", name);
+            assert!(m.audio().is_none(), "{}", name);
+        }
+        assert_eq!(
+            audio("Movie.2019.1080p.WEB-DL.Opus.5.1").format,
+            AudioFormat::Opus
+        );
+    }
+
+    #[test]
+    fn not_the_group() {
+        for name in [
+            "Movie.2019.1080p.BluRay.DTS-HD.MA.5.1",
+            "Movie.2019.1080p.BluRay.DTS-X",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.group(), None, "{}", name);
+            assert!(m.audio().is_some(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn channels_and_atmos() {
+        let a = audio(
+            "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD",
+        );
+        assert_eq!(a.format, AudioFormat::DolbyDigitalPlus);
+        assert_eq!(a.channels.as_deref(), Some("5.1"));
+        assert!(a.atmos);
+        assert_eq!(a.to_string(), "DD+ 5.1 Atmos");
+
+        let a = audio("Dune.2021.2160p.UHD.BluRay.REMUX.HDR.HEVC.TrueHD.7.1.Atmos-FGT");
+        assert_eq!(a.format, AudioFormat::TrueHd);
+        assert_eq!(a.channels.as_deref(), Some("7.1"));
+        assert!(a.atmos);
+
+        let a = audio("Dune.2021.2160p.UHD.BluRay.x265.TrueHD.Atmos.7.1-GROUP");
+        assert_eq!(a.channels.as_deref(), Some("7.1"));
+        assert!(a.atmos);
+
+        let a = audio("The.Walking.Dead.S05E03.1080p.WEB-DL.DD5.1.H.264-Cyphanix[rartv]");
+        assert_eq!(a.format, AudioFormat::DolbyDigital);
+        assert_eq!(a.channels.as_deref(), Some("5.1"));
+        assert!(!a.atmos);
+    }
+
+    #[test]
+    fn formats() {
+        for (name, format, channels, raw) in [
+            (
+                "Movie.2019.1080p.BluRay.DTS-HD.MA.5.1.x264-GROUP",
+                AudioFormat::DtsHdMa,
+                Some("5.1"),
+                "DTS-HD.MA.5.1",
+            ),
+            (
+                "Movie.2019.2160p.BluRay.DTS-X.7.1.HEVC-GROUP",
+                AudioFormat::DtsX,
+                Some("7.1"),
+                "DTS-X.7.1",
+            ),
+            (
+                "Movie.2019.1080p.WEB-DL.EAC3.x264-GROUP",
+                AudioFormat::DolbyDigitalPlus,
+                None,
+                "EAC3",
+            ),
+            (
+                "Movie.2019.1080p.BluRay.FLAC.2.0.x264-GROUP",
+                AudioFormat::Flac,
+                Some("2.0"),
+                "FLAC.2.0",
+            ),
+            (
+                "Movie.2019.1080p.WEBRip.Opus.x265-GROUP",
+                AudioFormat::Opus,
+                None,
+                "Opus",
+            ),
+            (
+                "Movie.2019.720p.WEBRip.AAC2.0.x264-GROUP",
+                AudioFormat::Aac,
+                Some("2.0"),
+                "AAC2.0",
+            ),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            let a = m.audio().unwrap();
+            assert_eq!(a.format, format, "{}", name);
+            assert_eq!(a.channels.as_deref(), channels, "{}", name);
+            assert_eq!(m.audio_raw(), Some(raw));
+            assert_eq!(m.title(), "Movie");
+        }
+    }
+
+    #[test]
+    fn format_round_trip() {
+        for format in [
+            AudioFormat::DolbyDigitalPlus,
+            AudioFormat::TrueHd,
+            AudioFormat::DtsHd,
+            AudioFormat::DtsHdMa,
+            AudioFormat::DtsX,
+            AudioFormat::Lpcm,
+        ] {
+            assert_eq!(AudioFormat::from(format.to_string().as_str()), format);
+        }
+    }
+}
+
+#[cfg(test)]
+mod bit_depth {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn markers() {
+        for (name, depth) in [
+            ("Rome.S01E11.The.Spoils.BluRay.10Bit.1080p.Dts.H265-d3g", 10),
+            ("Movie.2019.1080p.BluRay.8bit.x264-GROUP", 8),
+            ("Movie.2019.2160p.WEB-DL.12-bit.HEVC-GROUP", 12),
+            ("[Group] Anime - 01 [BD 1080p Hi10P FLAC]", 10),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.bit_depth(), Some(depth), "{}", name);
+        }
+
+        let m = Metadata::from("The.Walking.Dead.S05E03.1080p.WEB-DL.DD5.1.H.264-Cyphanix[rartv]")
+            .unwrap();
+        assert_eq!(m.bit_depth(), None);
+    }
+
+    #[test]
+    fn trims_title() {
+        let m = Metadata::from("Big Fish 10bit 1080p").unwrap();
+        assert_eq!(m.title(), "Big Fish");
+        assert_eq!(m.bit_depth(), Some(10));
+    }
+}
+
+#[cfg(test)]
+mod size {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn units() {
+        for (name, bytes) in [
+            (
+                "[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool",
+                750_000_000,
+            ),
+            (
+                "[TorrentCounter.to].Pacific.Rim.2.Uprising.2018.1080p.HC.HDRip.x264.[2GB]",
+                2_000_000_000,
+            ),
+            ("Lucy 2014 Dual-Audio WEBRip 1400Mb", 1_400_000_000),
+            ("Movie.2019.1080p.BluRay.x264.1.4GiB", 1_503_238_554),
+            ("Movie (2019) 720p WEBRip 850 KB", 850_000),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.size_bytes(), Some(bytes), "{}", name);
+        }
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.size_bytes(), None);
+    }
+
+    #[test]
+    fn trims_title() {
+        let name = "Big Fish 4.37GB";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.title(), "Big Fish");
+        assert_eq!(m.size_bytes(), Some(4_370_000_000));
+        let span = m.span("size").unwrap();
+        assert_eq!(&name[span.start..span.end], "4.37GB");
+    }
+
+    #[test]
+    fn after_dotted_year() {
+        let name = "Movie.2019.750MB.1080p";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.size_bytes(), Some(750_000_000));
+        assert_eq!(m.year(), Some(2019));
+        let span = m.span("size").unwrap();
+        assert_eq!(&name[span.start..span.end], "750MB");
+    }
+}
+
+#[cfg(test)]
+mod episode_title {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn after_episode_marker() {
+        for (name, title) in [
+            (
+                "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS",
+                "Made You Look",
+            ),
+            (
+                "The EXPANSE - S03 E01 - Fight or Flight (1080p - AMZN Web-DL)",
+                "Fight or Flight",
+            ),
+            (
+                "Rome.S01E11.The.Spoils.BluRay.10Bit.1080p.Dts.H265-d3g",
+                "The Spoils",
+            ),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.episode_title(), Some(title), "{}", name);
+            let span = m.span("episode_title").unwrap();
+            assert_eq!(
+                name[span.start..span.end].replace('.', " "),
+                title,
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn none() {
+        for name in [
+            "narcos.s01e10.1080p.bluray.x264-rovers",
+            "The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]",
+            "The Flash 2014 S01E01 HDTV x264-LOL[ettv]",
+            "Hercules (2014) 1080p BrRip H264 - YIFY",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.episode_title(), None, "{}", name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod air_date {
+    use crate::error::ParseWarning;
+    use crate::metadata::Metadata;
+    use crate::types::Date;
+
+    #[test]
+    fn orders_and_separators() {
+        for (name, date) in [
+            (
+                "The.Daily.Show.2019.03.14.Guest.720p.WEB.x264-TBS",
+                (2019, 3, 14),
+            ),
+            ("The Daily Show 2019-03-14 720p", (2019, 3, 14)),
+            ("Show 14-03-2019", (2019, 3, 14)),
+            ("Jimmy.Kimmel.Live.03.28.2021.720p.WEB-DL", (2021, 3, 28)),
+            ("Late Night 2021 11 02 WEB", (2021, 11, 2)),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.air_date(), Date::new(date.0, date.1, date.2), "{}", name);
+            assert_eq!(m.year(), Some(date.0));
+            assert!(m.is_daily());
+            assert!(m.is_show());
+            assert!(m.warnings().is_empty());
+        }
+
+        let m = Metadata::from("The.Daily.Show.2019.03.14.Guest.720p.WEB.x264-TBS").unwrap();
+        assert_eq!(m.title(), "The Daily Show");
+        assert_eq!(m.episode_title(), Some("Guest"));
+        assert_eq!(m.air_date().unwrap().to_string(), "2019-03-14");
+
+        let m = Metadata::from("Show 14-03-2019").unwrap();
+        assert_eq!(m.group(), None);
+        let m = Metadata::from("Show 14-03-2019-GRP").unwrap();
+        assert_eq!(m.group(), Some("GRP"));
+    }
+
+    #[test]
+    fn ambiguous() {
+        let m = Metadata::from("Show.03.04.2019.720p").unwrap();
+        assert_eq!(m.air_date(), Date::new(2019, 4, 3));
+        assert_eq!(m.warnings(), &[ParseWarning::AmbiguousDate]);
+
+        let m = Metadata::from("Show.04.04.2019.720p").unwrap();
+        assert!(m.warnings().is_empty());
+    }
+
+    #[test]
+    fn channel_layouts() {
+        for name in ["Movie.2012.5.1.DTS", "Movie AAC 5.1 2019"] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.air_date(), None, "{}", name);
+            assert!(!m.is_daily(), "{}", name);
+            assert!(!m.is_show(), "{}", name);
+            assert_eq!(m.title(), "Movie", "{}", name);
+        }
+        let m = Metadata::from("Movie AAC 5.1 2019").unwrap();
+        assert_eq!(m.year(), Some(2019));
+        assert_eq!(m.audio().unwrap().channels.as_deref(), Some("5.1"));
+    }
+
+    #[test]
+    fn not_a_date() {
+        let m = Metadata::from("Blade.Runner.2049.2017.HDRip").unwrap();
+        assert_eq!(m.air_date(), None);
+        assert!(!m.is_daily());
+
+        let m = Metadata::from("Show.2019.02.30.720p").unwrap();
+        assert_eq!(m.air_date(), None);
+
+        assert_eq!(Date::new(2020, 2, 29).map(|d| d.day), Some(29));
+        assert_eq!(Date::new(2019, 2, 29), None);
+    }
+}
+
+#[cfg(test)]
+mod season_pack {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn ranges() {
+        for (name, seasons) in [
+            (
+                "Show.S01-S05.Complete.1080p.BluRay.x264-GRP",
+                vec![1, 2, 3, 4, 5],
+            ),
+            ("Show Season 1-3 1080p WEB-DL", vec![1, 2, 3]),
+            ("Show.Seasons.1-4.1080p.BluRay", vec![1, 2, 3, 4]),
+            ("Show.S01.S02.S03.720p.HDTV", vec![1, 2, 3]),
+            ("Show.S02.1080p.BluRay.x264-GRP", vec![2]),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.seasons(), &seasons, "{}", name);
+            assert_eq!(m.season(), Some(seasons[0]));
+            assert_eq!(m.title(), "Show");
+            assert!(m.is_season_pack());
+            assert!(!m.is_complete_series());
+        }
+
+        for (name, group) in [
+            ("Show.S01-S05.Complete", None),
+            ("Show Season 1-3", None),
+            ("Show.S01-S05.Complete.1080p.BluRay.x264-GRP", Some("GRP")),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.group(), group, "{}", name);
+        }
+    }
+
+    #[test]
+    fn reversed_range() {
+        let m = Metadata::from("Show.S05-S01.720p").unwrap();
+        assert_eq!(m.season(), Some(5));
+        assert_eq!(m.seasons(), &vec![5]);
+        assert_eq!(m.title(), "Show");
+    }
+
+    #[test]
+    fn episodes_are_not_packs() {
+        let m = Metadata::from("The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]").unwrap();
+        assert_eq!(m.seasons(), &vec![5]);
+        assert!(!m.is_season_pack());
+
+        let m = Metadata::from("Dr. Stone S2 - 07 (1080p)").unwrap();
+        assert_eq!(m.seasons(), &vec![2]);
+        assert!(!m.is_season_pack());
+
+        let m = Metadata::from("Hercules (2014) 1080p BrRip H264 - YIFY").unwrap();
+        assert!(m.seasons().is_empty());
+        assert!(!m.is_season_pack());
+    }
+
+    #[test]
+    fn complete_series() {
+        let m = Metadata::from("Show.Complete.Series.1080p.BluRay").unwrap();
+        assert!(m.is_complete_series());
+        assert_eq!(m.title(), "Show");
+
+        let m = Metadata::from("Show The Complete Series 720p").unwrap();
+        assert!(m.is_complete_series());
+        assert_eq!(m.title(), "Show");
+
+        let m = Metadata::from("Show.S01-S05.Complete.1080p.BluRay.x264-GRP").unwrap();
+        assert!(!m.is_complete_series());
+    }
+}
+
+#[cfg(test)]
+mod anime {
+    use crate::metadata::Metadata;
+    use crate::parser::{Field, Parser};
+
+    #[test]
+    fn absolute_episode() {
+        let m = Metadata::from("[SubsPlease] Jujutsu Kaisen - 24 (1080p) [A1B2C3D4].mkv").unwrap();
+        assert_eq!(m.title(), "Jujutsu Kaisen");
+        assert_eq!(m.group(), Some("SubsPlease"));
+        assert_eq!(m.website(), None);
+        assert_eq!(m.absolute_episode(), Some(24));
+        assert_eq!(m.episode(), Some(24));
+        assert_eq!(m.season(), None);
+        assert_eq!(m.release_version(), None);
+        assert_eq!(m.resolution_raw(), Some("1080p"));
+        assert_eq!(m.extension(), Some("mkv"));
+        assert!(m.is_show());
+    }
+
+    #[test]
+    fn release_version() {
+        let m = Metadata::from("[SubsPlease] Jujutsu Kaisen - 24v2 (1080p)").unwrap();
+        assert_eq!(m.absolute_episode(), Some(24));
+        assert_eq!(m.release_version(), Some(2));
+        assert_eq!(m.title(), "Jujutsu Kaisen");
+    }
+
+    #[test]
+    fn batch() {
+        let m =
+            Metadata::from("[Erai-raws] Spy x Family - 01-12 [1080p][Multiple Subtitle]").unwrap();
+        assert_eq!(m.title(), "Spy x Family");
+        assert_eq!(m.absolute_episode(), Some(1));
+        assert_eq!(m.episodes(), &(1..=12).collect::<Vec<_>>());
+
+        let m = Metadata::from("[Judas] Vinland Saga (01-24) [BD 1080p]").unwrap();
+        assert_eq!(m.title(), "Vinland Saga");
+        assert_eq!(m.group(), Some("Judas"));
+        assert_eq!(m.episodes().len(), 24);
+    }
+
+    #[test]
+    fn seasons_win() {
+        let m = Metadata::from("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]").unwrap();
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(7));
+        assert_eq!(m.absolute_episode(), None);
+        assert_eq!(m.group(), Some("SubsPlease"));
+    }
+
+    #[test]
+    fn years_are_not_episodes() {
+        let m = Metadata::from("[Group] Show - 2019 [1080p]").unwrap();
+        assert_eq!(m.year(), Some(2019));
+        assert_eq!(m.absolute_episode(), None);
+        assert_eq!(m.episode(), None);
+        assert_eq!(m.title(), "Show");
+
+        let m = Metadata::from("[Group] Show - 1024 [1080p]").unwrap();
+        assert_eq!(m.absolute_episode(), Some(1024));
+        assert_eq!(m.year(), None);
+    }
+
+    #[test]
+    fn websites_are_not_fansubs() {
+        let m =
+            Metadata::from("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool")
+                .unwrap();
+        assert_eq!(m.website(), Some("www.UsaBit.com"));
+        assert_eq!(m.absolute_episode(), None);
+
+        let m = Metadata::from("[Judas] Re Zero 2020 - S01E01").unwrap();
+        assert_eq!(m.website(), Some("Judas"));
+        assert_eq!(m.absolute_episode(), None);
+    }
+
+    #[test]
+    fn disabled() {
+        let parser = Parser::builder().disable(Field::AbsoluteEpisode).build();
+        let m = parser
+            .parse("[SubsPlease] Jujutsu Kaisen - 24 (1080p)")
+            .unwrap();
+        assert_eq!(m.absolute_episode(), None);
+        assert_eq!(m.episode(), None);
+    }
+}
+
+#[cfg(test)]
+mod checksum {
+    use crate::checksum::crc32;
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn parsed() {
+        let m = Metadata::from("[SubsPlease] Jujutsu Kaisen - 24 (1080p) [A1B2C3D4].mkv").unwrap();
+        assert_eq!(m.checksum(), Some(0xA1B2_C3D4));
+        assert_eq!(m.absolute_episode(), Some(24));
+
+        let m = Metadata::from("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]").unwrap();
+        assert_eq!(m.checksum(), Some(0x3353_8C7C));
+
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.checksum(), None);
+    }
+
+    #[test]
+    fn ignored_by_other_fields() {
+        let m = Metadata::from("[SubsPlease] Show - 01 (1080p) [1D65E30D]").unwrap();
+        assert_eq!(m.checksum(), Some(0x1D65_E30D));
+        assert_eq!(m.season(), None);
+        assert_eq!(m.absolute_episode(), Some(1));
+        assert_eq!(m.title(), "Show");
+    }
+
+    #[test]
+    fn not_dates() {
+        let m = Metadata::from("[Group] Show - 01 (20190314) [1080p]").unwrap();
+        assert_eq!(m.checksum(), None);
+
+        let m = Metadata::from("[Group] Show - 01 (1080p) [20191234]").unwrap();
+        assert_eq!(m.checksum(), Some(0x2019_1234));
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(crc32(&b""[..]).unwrap(), 0);
+        assert_eq!(crc32(&b"123456789"[..]).unwrap(), 0xCBF4_3926);
+
+        let m = Metadata::from("[Group] Title - 01 [CBF43926].mkv").unwrap();
+        assert!(m.verify_checksum(&b"123456789"[..]).unwrap().unwrap());
+        assert!(!m.verify_checksum(&b"12345678"[..]).unwrap().unwrap());
+
+        let m = Metadata::from("Title.2019.1080p.mkv").unwrap();
+        assert!(m.verify_checksum(&b"123456789"[..]).is_none());
+    }
+}
+
+#[cfg(test)]
+mod parts {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn discs() {
+        let m = Metadata::from("Movie.2004.CD1.avi").unwrap();
+        assert_eq!(m.disc(), Some(1));
+        assert_eq!(m.title(), "Movie");
+        assert_eq!(m.year(), Some(2004));
+
+        let m = Metadata::from("Movie.Disc.2.1080p.BluRay").unwrap();
+        assert_eq!(m.disc(), Some(2));
+        assert_eq!(m.title(), "Movie");
+    }
+
+    #[test]
+    fn part_markers() {
+        for (name, title, part, total) in [
+            ("Doc.Part.1of6.720p.HDTV.x264", "Doc", 1, Some(6)),
+            (
+                "Planet.Earth.Part.3.of.11.720p",
+                "Planet Earth",
+                3,
+                Some(11),
+            ),
+            ("Movie.Pt.II.1080p", "Movie", 2, None),
+            ("Movie.Pt.IV.1080p", "Movie", 4, None),
+            ("Anthology.Vol.3.720p", "Anthology", 3, None),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.title(), title);
+            assert_eq!(m.part(), Some(part), "{}", name);
+            assert_eq!(m.total_parts(), total, "{}", name);
+            assert_eq!(m.disc(), None);
+        }
+    }
+
+    #[test]
+    fn not_seasons() {
+        let m = Metadata::from("Movie CD1 x264").unwrap();
+        assert_eq!(m.disc(), Some(1));
+        assert_eq!(m.season(), None);
+        assert_eq!(m.episode(), None);
+        assert_eq!(m.title(), "Movie");
+    }
+
+    #[test]
+    fn part_of_the_title() {
+        let m = Metadata::from("Kill.Bill.Vol.1.2003.1080p.BluRay").unwrap();
+        assert_eq!(m.part(), Some(1));
+        assert_eq!(m.title(), "Kill Bill Vol 1");
+
+        let m = Metadata::from("Harry Potter and the Deathly Hallows Part 1 (2010) 1080p").unwrap();
+        assert_eq!(m.part(), Some(1));
+        assert_eq!(m.title(), "Harry Potter and the Deathly Hallows Part 1");
+
+        let m = Metadata::from("Doc.Part.1of6.2004.avi").unwrap();
+        assert_eq!((m.part(), m.total_parts()), (Some(1), Some(6)));
+        assert_eq!(m.title(), "Doc Part 1of6");
+    }
+
+    #[test]
+    fn invalid_roman_numerals() {
+        for name in [
+            "Movie.Pt.IIII.1080p",
+            "Movie.Pt.IVX.1080p",
+            "Movie.Pt.XXXX.1080p",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.part(), None, "{}", name);
+        }
+        let m = Metadata::from("Movie.Pt.IX.1080p").unwrap();
+        assert_eq!(m.part(), Some(9));
+    }
+}
+
+#[cfg(test)]
+mod edition {
+    use crate::metadata::Metadata;
+    use crate::types::Edition;
+
+    #[test]
+    fn editions() {
+        for (name, title, edition) in [
+            (
+                "Movie.2010.Directors.Cut.720p.BluRay",
+                "Movie",
+                Edition::DirectorsCut,
+            ),
+            (
+                "Movie.2010.Director's.Cut.720p.BluRay",
+                "Movie",
+                Edition::DirectorsCut,
+            ),
+            (
+                "Kingdom.of.Heaven.2005.DC.1080p.BluRay",
+                "Kingdom of Heaven",
+                Edition::DirectorsCut,
+            ),
+            (
+                "Movie.2010.Theatrical.Cut.1080p",
+                "Movie",
+                Edition::Theatrical,
+            ),
+            (
+                "Blade.Runner.1982.The.Final.Cut.1080p.BluRay",
+                "Blade Runner",
+                Edition::FinalCut,
+            ),
+            (
+                "Movie.1984.REMASTERED.1080p.BluRay",
+                "Movie",
+                Edition::Remastered,
+            ),
+            (
+                "Movie.1962.Criterion.Collection.1080p",
+                "Movie",
+                Edition::Criterion,
+            ),
+            ("Dune.2021.IMAX.2160p.WEB-DL", "Dune", Edition::Imax),
+            (
+                "Aliens.1986.Special.Edition.1080p",
+                "Aliens",
+                Edition::SpecialEdition,
+            ),
+            (
+                "Jaws.1975.45th.Anniversary.Edition.1080p",
+                "Jaws",
+                Edition::AnniversaryEdition,
+            ),
+            ("Movie.2004.UNCUT.720p.DVDRip", "Movie", Edition::Uncut),
+            ("Movie.1999.Open.Matte.1080p", "Movie", Edition::OpenMatte),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.edition(), Some(edition), "{}", name);
+            assert_eq!(m.title(), title);
+        }
+    }
+
+    #[test]
+    fn dc_span() {
+        let name = "Kingdom.of.Heaven.2005.DC.1080p.BluRay";
+        let m = Metadata::from(name).unwrap();
+        let span = m.span("edition").unwrap();
+        assert_eq!(&name[span.start..span.end], "DC");
+        assert_eq!(m.title(), "Kingdom of Heaven");
+    }
+
+    #[test]
+    fn part_of_the_title() {
+        let m = Metadata::from("Uncut Gems (2019) 1080p").unwrap();
+        assert_eq!(m.edition(), None);
+        assert_eq!(m.title(), "Uncut Gems");
+
+        let m = Metadata::from("DC.League.of.Super.Pets.2022.1080p").unwrap();
+        assert_eq!(m.edition(), None);
+        assert_eq!(m.title(), "DC League of Super Pets");
+    }
+}
+
+#[cfg(test)]
+mod release_flags {
+    use crate::metadata::Metadata;
+    use crate::types::ReleaseFlags;
+
+    #[test]
+    fn scene_tags() {
+        for (name, flags) in [
+            (
+                "Movie.2019.REAL.PROPER.1080p.BluRay.x264-GRP",
+                ReleaseFlags::REAL | ReleaseFlags::PROPER,
+            ),
+            (
+                "Show.S01E01.iNTERNAL.720p.HDTV.x264-GRP",
+                ReleaseFlags::INTERNAL,
+            ),
+            (
+                "Movie.2019.LIMITED.DUBBED.REPACK.720p",
+                ReleaseFlags::LIMITED | ReleaseFlags::DUBBED | ReleaseFlags::REPACK,
+            ),
+            (
+                "Movie.2019.RERIP.SUBBED.720p",
+                ReleaseFlags::RERIP | ReleaseFlags::SUBBED,
+            ),
+            (
+                "Movie.2019.READNFO.CONVERT.720p",
+                ReleaseFlags::READNFO | ReleaseFlags::CONVERT,
+            ),
+            ("Movie.2019.DIRFIX.720p.BluRay-GRP", ReleaseFlags::DIRFIX),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.flags(), flags, "{}", name);
+        }
+
+        let m = Metadata::from("Real.Steel.2011.1080p.BluRay").unwrap();
+        assert!(m.flags().is_empty());
+        assert_eq!(m.title(), "Real Steel");
+
+        let m = Metadata::from("REAL.STEEL.2011.720p.BluRay").unwrap();
+        assert!(m.flags().is_empty());
+        assert_eq!(m.title(), "REAL STEEL");
+
+        let m = Metadata::from("THE.REAL.HOUSEWIVES.S01E01.720p").unwrap();
+        assert!(m.flags().is_empty());
+        assert_eq!(m.title(), "THE REAL HOUSEWIVES");
+    }
+
+    #[test]
+    fn booleans() {
+        let m = Metadata::from("Movie.2019.REAL.PROPER.1080p.BluRay.x264-GRP").unwrap();
+        assert!(m.proper());
+        assert!(!m.repack());
+        assert_eq!(m.title(), "Movie");
+    }
+
+    #[test]
+    fn fixes() {
+        for name in [
+            "Movie.2019.NFOFIX-GRP",
+            "Movie.2019.DIRFIX-GRP",
+            "Show.S01E01.SYNCFIX.720p-GRP",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert!(m.flags().is_fix(), "{}", name);
+        }
+        assert!(!(ReleaseFlags::PROPER | ReleaseFlags::REPACK).is_fix());
+    }
+
+    #[test]
+    fn display() {
+        let flags = ReleaseFlags::REAL | ReleaseFlags::PROPER;
+        assert_eq!(flags.to_string(), "PROPER REAL");
+        assert_eq!(flags.names().collect::<Vec<_>>(), ["PROPER", "REAL"]);
+        assert_eq!(
+            ReleaseFlags::from(vec!["real".to_string(), "proper".to_string()]),
+            flags
+        );
+        assert_eq!(ReleaseFlags::empty().to_string(), "");
+    }
+}
+
+#[cfg(test)]
+mod languages {
+    use crate::metadata::Metadata;
+    use crate::Language;
+
+    #[test]
+    fn names() {
+        let m = Metadata::from("Movie.Name.2019.GERMAN.DL.1080p.BluRay.x264-GROUP").unwrap();
+        assert_eq!(m.language(), Some("GERMAN"));
+        assert_eq!(m.languages(), [Language::German]);
+
+        let m = Metadata::from("Film (2020) [1080p] Hindi Tamil Telugu").unwrap();
+        assert_eq!(
+            m.languages(),
+            [Language::Hindi, Language::Tamil, Language::Telugu]
+        );
+        assert_eq!(m.title(), "Film");
+    }
+
+    #[test]
+    fn abbreviations() {
+        for (name, language) in [
+            ("Movie.2018.GER.1080p.BluRay", Language::German),
+            ("Movie.2018.ITA.1080p.BluRay", Language::Italian),
+            ("Movie.2018.ESP.1080p.BluRay", Language::Spanish),
+            ("Movie.2018.JAP.1080p.BluRay", Language::Japanese),
+            ("Movie.2018.KOR.1080p.BluRay", Language::Korean),
+            ("Movie.2018.HIN.1080p.BluRay", Language::Hindi),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.languages(), [language], "{}", name);
+        }
+    }
+
+    #[test]
+    fn composite() {
+        let m = Metadata::from("Some.Film.2015.rus.eng.BDRip.720p").unwrap();
+        assert_eq!(m.language(), Some("rus.eng"));
+        assert_eq!(m.languages(), [Language::Russian, Language::English]);
+
+        let m = Metadata::from("Show.S01E02.ENG-ITA.1080p.WEB").unwrap();
+        assert_eq!(m.languages(), [Language::English, Language::Italian]);
+        assert_eq!(m.title(), "Show");
+
+        let m = Metadata::from("Movie.2019.ENG-ITA.1080p").unwrap();
+        assert_eq!(m.languages(), [Language::English, Language::Italian]);
+        assert_eq!(m.group(), None);
+    }
+
+    #[test]
+    fn deduplicated() {
+        let m = Metadata::from("Amelie.2001.TRUEFRENCH.FRENCH.720p").unwrap();
+        assert_eq!(m.language(), Some("TRUEFRENCH"));
+        assert_eq!(m.languages(), [Language::French]);
+    }
+
+    #[test]
+    fn name_in_title() {
+        let m = Metadata::from("The.Italian.Job.2003.1080p.BluRay.x264").unwrap();
+        assert_eq!(m.title(), "The Italian Job");
+        assert!(m.languages().is_empty());
+    }
+
+    #[test]
+    fn all_caps_titles() {
+        for (name, title) in [
+            (
+                "DAN.IN.REAL.LIFE.2007.720p.BluRay.x264-GRP",
+                "DAN IN REAL LIFE",
+            ),
+            ("POL.2019.1080p", "POL"),
+            ("HUN.Movie.2019", "HUN Movie"),
+            ("THE.ITALIAN.JOB.2003.720p", "THE ITALIAN JOB"),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.title(), title, "{}", name);
+            assert!(m.languages().is_empty(), "{}", name);
+        }
+        let m = Metadata::from("THE.ITALIAN.JOB.2003.ITALIAN.720p").unwrap();
+        assert_eq!(m.title(), "THE ITALIAN JOB");
+        assert_eq!(m.languages(), [Language::Italian]);
+    }
+
+    #[test]
+    fn iso_codes() {
+        assert_eq!(Language::German.iso639_1(), Some("de"));
+        assert_eq!(Language::German.iso639_2(), "deu");
+        assert_eq!(Language::Multi.iso639_1(), None);
+        assert_eq!(Language::Multi.to_string(), "mul");
+        assert_eq!(Language::Japanese.to_string(), "ja");
+    }
+}
+
+#[cfg(test)]
+mod subtitles {
+    use crate::metadata::Metadata;
+    use crate::{Language, Subtitle};
+
+    fn languages(m: &Metadata) -> Vec<Option<Language>> {
+        m.subtitles().iter().map(|s| s.language).collect()
+    }
+
+    #[test]
+    fn release_tags() {
+        for (name, language) in [
+            ("Movie.2019.1080p.WEB-DL.ESub-GRP", Language::English),
+            ("Show.S01E02.720p.HDTV.Multi-Subs", Language::Multi),
+            ("Film.2018.1080p.BluRay.NLSubs", Language::Dutch),
+            ("Amelie.2001.VOSTFR.720p", Language::French),
+            ("Movie.2019.SUBFRENCH.720p", Language::French),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(languages(&m), [Some(language)], "{}", name);
+        }
+        let m = Metadata::from("Movie.2019.1080p.WEB-DL.ESub-GRP").unwrap();
+        assert_eq!(m.group(), Some("GRP"));
+    }
+
+    #[test]
+    fn not_audio_languages() {
+        let m = Metadata::from("Movie.2019.ENG.Subs.1080p").unwrap();
+        assert_eq!(languages(&m), [Some(Language::English)]);
+        assert!(m.languages().is_empty());
+
+        let m = Metadata::from("Show.S01E02.720p.HDTV.Multi-Subs").unwrap();
+        assert_eq!(m.language(), None);
+    }
+
+    #[test]
+    fn sidecar_forced() {
+        let m = Metadata::from_subtitle_file("Movie.2019.en.forced.srt").unwrap();
+        assert_eq!(m.title(), "Movie");
+        assert_eq!(m.year(), Some(2019));
+        assert_eq!(m.extension(), Some("srt"));
+        assert_eq!(
+            m.subtitles(),
+            [Subtitle {
+                language: Some(Language::English),
+                forced: true,
+                sdh: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn sidecar_sdh() {
+        let m = Metadata::from_subtitle_file("Show.S01E02.720p.HDTV.eng.sdh.srt").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.episode(), Some(2));
+        let subtitle = m.subtitles()[0];
+        assert_eq!(subtitle.language, Some(Language::English));
+        assert!(subtitle.sdh);
+        assert_eq!(subtitle.to_string(), "en SDH");
+
+        let m = Metadata::from_subtitle_file("Movie.2019.pt.cc.vtt").unwrap();
+        assert_eq!(m.subtitles()[0].language, Some(Language::Portuguese));
+        assert!(m.subtitles()[0].sdh);
+    }
+
+    #[test]
+    fn sidecar_languages() {
+        for (name, language) in [
+            ("Movie.2019.English.srt", Language::English),
+            ("Movie.2019.pt-BR.srt", Language::Portuguese),
+            ("Movie.2019.zh_Hans.forced.ass", Language::Chinese),
+        ] {
+            let m = Metadata::from_subtitle_file(name).unwrap();
+            assert_eq!(m.subtitles()[0].language, Some(language), "{}", name);
+            assert_eq!(m.title(), "Movie", "{}", name);
+        }
+    }
+
+    #[test]
+    fn tag_at_the_end() {
+        let m = Metadata::from("Movie.2019.1080p.ESub").unwrap();
+        assert_eq!(m.extension(), None);
+        assert_eq!(languages(&m), [Some(Language::English)]);
+    }
+
+    #[test]
+    fn sidecar_without_tags() {
+        let m = Metadata::from_subtitle_file("Life.on.Mars.(US).S00E01.srt").unwrap();
+        assert_eq!(m.title(), "Life on Mars");
+        assert_eq!(m.extension(), Some("srt"));
+        assert_eq!(m.subtitles(), [Subtitle::default()]);
+        assert_eq!(m.subtitles()[0].to_string(), "und");
+    }
+
+    #[test]
+    fn not_a_sidecar() {
+        let m = Metadata::from_subtitle_file("Movie.2019.en.mkv").unwrap();
+        assert_eq!(m.title(), "Movie");
+        assert_eq!(m.extension(), Some("mkv"));
+        assert!(m.subtitles().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod path {
+    use crate::metadata::Metadata;
+    use crate::{Resolution, Source};
+    use std::path::Path;
+
+    #[test]
+    fn season_folder() {
+        let m =
+            Metadata::from_path(Path::new("/tv/Breaking Bad/Season 02/05 - Breakage.mkv")).unwrap();
+        assert_eq!(m.title(), "Breaking Bad");
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(5));
+        assert_eq!(m.episode_title(), Some("Breakage"));
+        assert_eq!(m.extension(), Some("mkv"));
+        assert_eq!(m.group(), None);
+        assert_eq!(m.source("title"), Some(Source::ShowFolder));
+        assert_eq!(m.source("season"), Some(Source::SeasonFolder));
+        assert_eq!(m.source("episode"), Some(Source::FileName));
+        assert_eq!(m.source("episode_title"), Some(Source::FileName));
+
+        let file = "05 - Breakage.mkv";
+        let span = m.span("episode_title").unwrap();
+        assert_eq!(&file[span.start..span.end], "Breakage");
+        let span = m.span("season").unwrap();
+        assert_eq!(&"Season 02"[span.start..span.end], "02");
+    }
+
+    #[test]
+    fn show_name() {
+        let m = Metadata::from_path(Path::new("/tv/The 100/Season 1/01 - Pilot.mkv")).unwrap();
+        assert_eq!(m.title(), "The 100");
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), Some(1));
+        assert_eq!(m.year(), None);
+
+        let m =
+            Metadata::from_path(Path::new("Doctor.Who.2005/S03/Doctor.Who.S03E01.mkv")).unwrap();
+        assert_eq!(m.title(), "Doctor Who");
+        assert_eq!(m.year(), Some(2005));
+        assert_eq!(m.source("year"), Some(Source::ShowFolder));
+    }
+
+    #[test]
+    fn specials_folder() {
+        let m =
+            Metadata::from_path(Path::new("Doctor Who/Specials/01 The Runaway Bride.avi")).unwrap();
+        assert_eq!(m.title(), "Doctor Who");
+        assert_eq!(m.season(), Some(0));
+        assert_eq!(m.episode(), Some(1));
+        assert_eq!(m.episode_title(), Some("The Runaway Bride"));
+    }
+
+    #[test]
+    fn release_folder() {
+        let m = Metadata::from_path(Path::new("/downloads/Show.S02.1080p.WEB-DL-GROUP/E03.mkv"))
+            .unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(3));
+        assert_eq!(m.resolution(), Some(&Resolution::P1080));
+        assert_eq!(m.group(), Some("GROUP"));
+        assert_eq!(m.source("resolution"), Some(Source::ShowFolder));
+        assert_eq!(m.source("episode"), Some(Source::FileName));
+    }
+
+    #[test]
+    fn file_name_first() {
+        let m = Metadata::from_path(Path::new(
+            "/movies/Inception (2010) 1080p/Inception.2010.720p.BluRay.x264.mkv",
+        ))
+        .unwrap();
+        assert_eq!(m.title(), "Inception");
+        assert_eq!(m.year(), Some(2010));
+        assert_eq!(m.resolution(), Some(&Resolution::P720));
+        assert_eq!(m.source("title"), Some(Source::FileName));
+        assert_eq!(m.source("resolution"), Some(Source::FileName));
+        // Without a season folder, leading numbers are left alone.
+        let m = Metadata::from_path(Path::new("/movies/2001.A.Space.Odyssey.1968.mkv")).unwrap();
+        assert_eq!(m.title(), "2001 A Space Odyssey");
+        assert_eq!(m.episode(), None);
+    }
+
+    #[test]
+    fn missing_title() {
+        assert!(Metadata::from_path(Path::new("/Season 01/E01.mkv")).is_err());
+    }
+}
+
+#[cfg(test)]
+mod torrent {
+    use crate::metadata::Metadata;
+    use crate::{Quality, Source};
+    use std::path::Path;
+
+    #[test]
+    fn season_pack() {
+        let files = [
+            "Show.S02.1080p.WEB-DL-GROUP/E01.mkv",
+            "Show.S02.1080p.WEB-DL-GROUP/E02.mkv",
+            "Show.S02.1080p.WEB-DL-GROUP/Show.S02E03.mkv",
+        ];
+        let parsed = Metadata::from_torrent("Show.S02.1080p.WEB-DL-GROUP", &files);
+        assert_eq!(parsed.len(), 3);
+        for (i, (file, m)) in parsed.iter().enumerate() {
+            assert_eq!(*file, Path::new(files[i]));
+            assert_eq!(m.title(), "Show");
+            assert_eq!(m.season(), Some(2));
+            assert_eq!(m.episode(), Some(i as i32 + 1));
+            assert_eq!(m.quality(), Some(&Quality::WebDl));
+            assert_eq!(m.group(), Some("GROUP"));
+        }
+        assert_eq!(parsed[2].1.source("title"), Some(Source::FileName));
+    }
+
+    #[test]
+    fn skips_extras() {
+        let files = [
+            "Movie.2019.1080p.BluRay.x264-GRP.mkv",
+            "Movie.2019.1080p.BluRay.x264-GRP.nfo",
+            "Sample/movie.2019.1080p.sample.mkv",
+            "Samples/b.mkv",
+            "grp-movie-sample.mkv",
+            "Featurettes/Making Of.mkv",
+            "Behind The Scenes/Stunts.mkv",
+            "movie-trailer.mp4",
+        ];
+        let parsed = Metadata::from_torrent("Movie.2019.1080p.BluRay.x264-GRP", &files);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].0, Path::new(files[0]));
+    }
+
+    #[test]
+    fn season_folders() {
+        let files = ["Season 1/01 - Pilot.mkv", "Season 2/03.mkv"];
+        let parsed = Metadata::from_torrent("Show.The.Complete.Series.720p.BluRay", &files);
+        let (_, m) = &parsed[0];
+        assert_eq!(m.title(), "Show");
+        assert_eq!((m.season(), m.episode()), (Some(1), Some(1)));
+        assert_eq!(m.episode_title(), Some("Pilot"));
+        assert!(m.is_complete_series());
+        assert_eq!(m.source("season"), Some(Source::SeasonFolder));
+        assert_eq!(m.source("title"), Some(Source::Torrent));
+        let (_, m) = &parsed[1];
+        assert_eq!((m.season(), m.episode()), (Some(2), Some(3)));
+        assert_eq!(m.episode_title(), None);
+        assert_eq!(m.extension(), Some("mkv"));
+    }
+
+    #[test]
+    fn batch_fields_stay_with_the_torrent() {
+        let parsed = Metadata::from_torrent(
+            "[Group] Show Batch (01-12) [1080p] [ABCDEF12] [4.37GB]",
+            &["[Group] Show - 02.mkv"],
+        );
+        let (_, m) = &parsed[0];
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.episode(), Some(2));
+        assert_eq!(m.episodes(), &vec![2]);
+        assert_eq!(m.checksum(), None);
+        assert_eq!(m.size_bytes(), None);
+        assert_eq!(m.group(), Some("Group"));
+        assert_eq!(m.source("resolution"), Some(Source::Torrent));
+    }
+
+    #[test]
+    fn torrent_outranks_subfolders() {
+        let files = [
+            "Show.S02.1080p.WEB-DL-GROUP/Subs/E01.srt",
+            "Show.S02.1080p.WEB-DL-GROUP/Season 3/E01.mkv",
+        ];
+        let parsed = Metadata::from_torrent("Show.S02.1080p.WEB-DL-GROUP", &files);
+        let (_, m) = &parsed[0];
+        assert_eq!(m.title(), "Show");
+        assert_eq!((m.season(), m.episode()), (Some(2), Some(1)));
+        assert_eq!(m.source("title"), Some(Source::Torrent));
+        let (_, m) = &parsed[1];
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.season(), Some(3));
+        assert_eq!(m.source("season"), Some(Source::SeasonFolder));
+    }
+
+    #[test]
+    fn leading_episode_from_torrent_season() {
+        let parsed = Metadata::from_torrent("Show.S03.720p.HDTV", &["04 - The Name.mkv"]);
+        let (_, m) = &parsed[0];
+        assert_eq!(m.title(), "Show");
+        assert_eq!((m.season(), m.episode()), (Some(3), Some(4)));
+        assert_eq!(m.episode_title(), Some("The Name"));
+    }
+}
@@ -0,0 +1,920 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Lowercases `raw` and strips everything but letters and digits so that
+/// `"H.264/"`, `"h264"` and `"H264"` all normalize to the same key.
+fn normalize(raw: &str) -> String {
+    raw.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Byte range of a match within the parsed name, usable to slice it as
+/// `&name[span.start..span.end]`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+}
+
+/// Calendar date, e.g. the air date of a daily show.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Returns `None` when the day does not exist, e.g. February 30th.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days)
+            .contains(&day)
+            .then_some(Date { year, month, day })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Source of the release, as detected by `pattern::QUALITY`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub enum Quality {
+    Cam,
+    HdCam,
+    Telesync,
+    HdTv,
+    PdTv,
+    Web,
+    WebDl,
+    WebRip,
+    HdRip,
+    BrRip,
+    BluRay,
+    DvdRip,
+    DvdScr,
+    Other(String),
+}
+
+impl From<&str> for Quality {
+    fn from(raw: &str) -> Self {
+        let key = normalize(raw);
+        match key.trim_start_matches("ppv") {
+            "cam" | "camrip" => Quality::Cam,
+            "hdcam" => Quality::HdCam,
+            "ts" => Quality::Telesync,
+            "hdtv" => Quality::HdTv,
+            "pdtv" => Quality::PdTv,
+            "web" => Quality::Web,
+            "webdl" | "webdldvdrip" | "webdvdrip" => Quality::WebDl,
+            "webrip" | "wbbrip" | "ebbrip" => Quality::WebRip,
+            "hdrip" => Quality::HdRip,
+            "brrip" => Quality::BrRip,
+            "bluray" => Quality::BluRay,
+            "dvdrip" => Quality::DvdRip,
+            "dvdscr" => Quality::DvdScr,
+            _ => Quality::Other(raw.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Quality::Cam => "CAM",
+            Quality::HdCam => "HDCAM",
+            Quality::Telesync => "TS",
+            Quality::HdTv => "HDTV",
+            Quality::PdTv => "PDTV",
+            Quality::Web => "WEB",
+            Quality::WebDl => "WEB-DL",
+            Quality::WebRip => "WEBRip",
+            Quality::HdRip => "HDRip",
+            Quality::BrRip => "BRRip",
+            Quality::BluRay => "BluRay",
+            Quality::DvdRip => "DVDRip",
+            Quality::DvdScr => "DVDScr",
+            Quality::Other(raw) => raw,
+        })
+    }
+}
+
+impl From<String> for Quality {
+    fn from(raw: String) -> Self {
+        Quality::from(raw.as_str())
+    }
+}
+
+impl From<Quality> for String {
+    fn from(value: Quality) -> Self {
+        value.to_string()
+    }
+}
+
+/// Video codec, as detected by `pattern::CODEC`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub enum VideoCodec {
+    Xvid,
+    H264,
+    H265,
+    Other(String),
+}
+
+impl From<&str> for VideoCodec {
+    fn from(raw: &str) -> Self {
+        match normalize(raw).as_str() {
+            "xvid" => VideoCodec::Xvid,
+            "x264" | "h264" => VideoCodec::H264,
+            "x265" | "h265" | "hevc" | "hev" => VideoCodec::H265,
+            _ => VideoCodec::Other(raw.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            VideoCodec::Xvid => "XviD",
+            VideoCodec::H264 => "H.264",
+            VideoCodec::H265 => "H.265",
+            VideoCodec::Other(raw) => raw,
+        })
+    }
+}
+
+impl From<String> for VideoCodec {
+    fn from(raw: String) -> Self {
+        VideoCodec::from(raw.as_str())
+    }
+}
+
+impl From<VideoCodec> for String {
+    fn from(value: VideoCodec) -> Self {
+        value.to_string()
+    }
+}
+
+/// Vertical resolution, as detected by `pattern::RESOLUTION`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub enum Resolution {
+    P360,
+    P480,
+    P576,
+    P720,
+    P1080,
+    P1440,
+    P2160,
+    P4320,
+    Other(String),
+}
+
+impl From<&str> for Resolution {
+    fn from(raw: &str) -> Self {
+        match normalize(raw).as_str() {
+            "360p" => Resolution::P360,
+            "480p" => Resolution::P480,
+            "576p" => Resolution::P576,
+            "720p" => Resolution::P720,
+            "1080p" => Resolution::P1080,
+            "1440p" => Resolution::P1440,
+            "2160p" => Resolution::P2160,
+            "4320p" => Resolution::P4320,
+            _ => Resolution::Other(raw.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Resolution::P360 => "360p",
+            Resolution::P480 => "480p",
+            Resolution::P576 => "576p",
+            Resolution::P720 => "720p",
+            Resolution::P1080 => "1080p",
+            Resolution::P1440 => "1440p",
+            Resolution::P2160 => "2160p",
+            Resolution::P4320 => "4320p",
+            Resolution::Other(raw) => raw,
+        })
+    }
+}
+
+impl From<String> for Resolution {
+    fn from(raw: String) -> Self {
+        Resolution::from(raw.as_str())
+    }
+}
+
+impl From<Resolution> for String {
+    fn from(value: Resolution) -> Self {
+        value.to_string()
+    }
+}
+
+/// Audio codec, as detected by `pattern::AUDIO`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub enum AudioFormat {
+    Mp3,
+    Aac,
+    DolbyDigital,
+    DolbyDigitalPlus,
+    TrueHd,
+    Dts,
+    DtsHd,
+    DtsHdMa,
+    DtsX,
+    Flac,
+    Opus,
+    Lpcm,
+    DualAudio,
+    Line,
+    Other(String),
+}
+
+impl From<&str> for AudioFormat {
+    fn from(raw: &str) -> Self {
+        // `normalize` drops the `+` of `DD+`.
+        if raw.trim_end().ends_with('+') && normalize(raw) == "dd" {
+            return AudioFormat::DolbyDigitalPlus;
+        }
+        match normalize(raw).as_str() {
+            "mp3" => AudioFormat::Mp3,
+            "aac" | "aac20" => AudioFormat::Aac,
+            "dd" | "dd51" | "ac3" | "ac351" => AudioFormat::DolbyDigital,
+            "ddp" | "eac3" => AudioFormat::DolbyDigitalPlus,
+            "truehd" => AudioFormat::TrueHd,
+            "dts" => AudioFormat::Dts,
+            "dtshd" => AudioFormat::DtsHd,
+            "dtshdma" => AudioFormat::DtsHdMa,
+            "dtsx" => AudioFormat::DtsX,
+            "flac" => AudioFormat::Flac,
+            "opus" => AudioFormat::Opus,
+            "lpcm" | "pcm" => AudioFormat::Lpcm,
+            "dualaudio" => AudioFormat::DualAudio,
+            "line" => AudioFormat::Line,
+            _ => AudioFormat::Other(raw.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for AudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AudioFormat::Mp3 => "MP3",
+            AudioFormat::Aac => "AAC",
+            AudioFormat::DolbyDigital => "DD",
+            AudioFormat::DolbyDigitalPlus => "DD+",
+            AudioFormat::TrueHd => "TrueHD",
+            AudioFormat::Dts => "DTS",
+            AudioFormat::DtsHd => "DTS-HD",
+            AudioFormat::DtsHdMa => "DTS-HD MA",
+            AudioFormat::DtsX => "DTS:X",
+            AudioFormat::Flac => "FLAC",
+            AudioFormat::Opus => "Opus",
+            AudioFormat::Lpcm => "LPCM",
+            AudioFormat::DualAudio => "Dual Audio",
+            AudioFormat::Line => "LiNE",
+            AudioFormat::Other(raw) => raw,
+        })
+    }
+}
+
+impl From<String> for AudioFormat {
+    fn from(raw: String) -> Self {
+        AudioFormat::from(raw.as_str())
+    }
+}
+
+impl From<AudioFormat> for String {
+    fn from(value: AudioFormat) -> Self {
+        value.to_string()
+    }
+}
+
+/// Audio track description, e.g. `DDP5.1.Atmos`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Audio {
+    pub format: AudioFormat,
+    /// Channel layout such as `2.0`, `5.1` or `7.1`.
+    pub channels: Option<String>,
+    /// Dolby Atmos object audio on top of the base format.
+    pub atmos: bool,
+}
+
+impl Audio {
+    pub(crate) fn from_captures(caps: &regex::Captures<'_>) -> Option<Self> {
+        let format = caps.name("format")?.as_str();
+        let channels = caps.name("channels").map(|m| {
+            let digits: Vec<char> = m.as_str().chars().filter(char::is_ascii_digit).collect();
+            format!("{}.{}", digits[0], digits[1])
+        });
+        Some(Audio {
+            format: AudioFormat::from(format),
+            channels,
+            atmos: caps.name("atmos").is_some() || caps.name("atmos_first").is_some(),
+        })
+    }
+}
+
+impl fmt::Display for Audio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format)?;
+        if let Some(channels) = &self.channels {
+            write!(f, " {}", channels)?;
+        }
+        if self.atmos {
+            f.write_str(" Atmos")?;
+        }
+        Ok(())
+    }
+}
+
+/// Dynamic range format, as detected by `pattern::DYNAMIC_RANGE`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum DynamicRange {
+    Sdr,
+    Hdr,
+    Hdr10,
+    Hdr10Plus,
+    DolbyVision,
+    Hlg,
+}
+
+impl DynamicRange {
+    fn from_raw(raw: &str) -> Option<Self> {
+        // `normalize` drops the `+` of `HDR10+`.
+        if raw.ends_with('+') {
+            return Some(DynamicRange::Hdr10Plus);
+        }
+        match normalize(raw).as_str() {
+            "sdr" => Some(DynamicRange::Sdr),
+            "hdr" => Some(DynamicRange::Hdr),
+            "hdr10" => Some(DynamicRange::Hdr10),
+            "hdr10plus" => Some(DynamicRange::Hdr10Plus),
+            "dv" | "dovi" | "dolbyvision" => Some(DynamicRange::DolbyVision),
+            "hlg" => Some(DynamicRange::Hlg),
+            _ => None,
+        }
+    }
+
+    /// Collects the distinct formats among `raw` matches, in order of
+    /// appearance, so that `DV HDR10` yields `[DolbyVision, Hdr10]`.
+    pub(crate) fn from_matches<'a>(raw: impl Iterator<Item = &'a str>) -> Vec<Self> {
+        let mut ranges = Vec::new();
+        for range in raw.filter_map(DynamicRange::from_raw) {
+            if !ranges.contains(&range) {
+                ranges.push(range);
+            }
+        }
+        ranges
+    }
+}
+
+impl fmt::Display for DynamicRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DynamicRange::Sdr => "SDR",
+            DynamicRange::Hdr => "HDR",
+            DynamicRange::Hdr10 => "HDR10",
+            DynamicRange::Hdr10Plus => "HDR10+",
+            DynamicRange::DolbyVision => "DV",
+            DynamicRange::Hlg => "HLG",
+        })
+    }
+}
+
+/// Spoken language, as detected by `pattern::LANGUAGE`. Serializes as its
+/// `Display` code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+#[non_exhaustive]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Portuguese,
+    Russian,
+    Japanese,
+    Korean,
+    Chinese,
+    Hindi,
+    Arabic,
+    Dutch,
+    Polish,
+    Turkish,
+    Swedish,
+    Norwegian,
+    Danish,
+    Finnish,
+    Czech,
+    Hungarian,
+    Greek,
+    Hebrew,
+    Thai,
+    Vietnamese,
+    Ukrainian,
+    Tamil,
+    Telugu,
+    /// Several audio tracks, as in `MULTi`.
+    Multi,
+}
+
+impl Language {
+    /// Accepts English names as well as ISO 639-1 and 639-2 codes.
+    pub(crate) fn from_raw(raw: &str) -> Option<Self> {
+        Some(match normalize(raw).as_str() {
+            "english" | "eng" | "en" => Language::English,
+            "french" | "truefrench" | "vff" | "vfq" | "vfi" | "fre" | "fra" | "fr" => {
+                Language::French
+            }
+            "german" | "ger" | "deu" | "de" => Language::German,
+            "spanish" | "castellano" | "latino" | "esp" | "spa" | "es" => Language::Spanish,
+            "italian" | "ita" | "it" => Language::Italian,
+            "portuguese" | "por" | "pt" => Language::Portuguese,
+            "russian" | "rus" | "ru" => Language::Russian,
+            "japanese" | "jap" | "jpn" | "ja" => Language::Japanese,
+            "korean" | "kor" | "ko" => Language::Korean,
+            "chinese" | "mandarin" | "cantonese" | "chi" | "zho" | "zh" => Language::Chinese,
+            "hindi" | "hin" | "hi" => Language::Hindi,
+            "arabic" | "ara" | "ar" => Language::Arabic,
+            "dutch" | "dut" | "nld" | "nl" => Language::Dutch,
+            "polish" | "pol" | "pl" => Language::Polish,
+            "turkish" | "tur" | "tr" => Language::Turkish,
+            "swedish" | "swe" | "sv" => Language::Swedish,
+            "norwegian" | "nor" | "no" => Language::Norwegian,
+            "danish" | "dan" | "da" => Language::Danish,
+            "finnish" | "fin" | "fi" => Language::Finnish,
+            "czech" | "cze" | "cs" | "ces" => Language::Czech,
+            "hungarian" | "hun" | "hu" => Language::Hungarian,
+            "greek" | "gre" | "el" | "ell" => Language::Greek,
+            "hebrew" | "heb" | "he" => Language::Hebrew,
+            "thai" | "tha" | "th" => Language::Thai,
+            "vietnamese" | "vie" | "vi" => Language::Vietnamese,
+            "ukrainian" | "ukr" | "uk" => Language::Ukrainian,
+            "tamil" | "tam" | "ta" => Language::Tamil,
+            "telugu" | "tel" | "te" => Language::Telugu,
+            "multi" | "mul" => Language::Multi,
+            _ => return None,
+        })
+    }
+
+    /// Collects the distinct languages among `raw` matches, in order of
+    /// appearance, splitting composite tags such as `rus.eng`.
+    pub(crate) fn from_matches<'a>(raw: impl Iterator<Item = &'a str>) -> Vec<Self> {
+        let mut languages = Vec::new();
+        for language in raw
+            .flat_map(|tag| tag.split(['.', '-']))
+            .filter_map(Language::from_raw)
+        {
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+        languages
+    }
+
+    /// Two letter ISO 639-1 code, e.g. `de`. `None` for `Multi`.
+    pub fn iso639_1(self) -> Option<&'static str> {
+        Some(match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::Italian => "it",
+            Language::Portuguese => "pt",
+            Language::Russian => "ru",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Chinese => "zh",
+            Language::Hindi => "hi",
+            Language::Arabic => "ar",
+            Language::Dutch => "nl",
+            Language::Polish => "pl",
+            Language::Turkish => "tr",
+            Language::Swedish => "sv",
+            Language::Norwegian => "no",
+            Language::Danish => "da",
+            Language::Finnish => "fi",
+            Language::Czech => "cs",
+            Language::Hungarian => "hu",
+            Language::Greek => "el",
+            Language::Hebrew => "he",
+            Language::Thai => "th",
+            Language::Vietnamese => "vi",
+            Language::Ukrainian => "uk",
+            Language::Tamil => "ta",
+            Language::Telugu => "te",
+            Language::Multi => return None,
+        })
+    }
+
+    /// Three letter ISO 639-2/T code, e.g. `deu`.
+    pub fn iso639_2(self) -> &'static str {
+        match self {
+            Language::English => "eng",
+            Language::French => "fra",
+            Language::German => "deu",
+            Language::Spanish => "spa",
+            Language::Italian => "ita",
+            Language::Portuguese => "por",
+            Language::Russian => "rus",
+            Language::Japanese => "jpn",
+            Language::Korean => "kor",
+            Language::Chinese => "zho",
+            Language::Hindi => "hin",
+            Language::Arabic => "ara",
+            Language::Dutch => "nld",
+            Language::Polish => "pol",
+            Language::Turkish => "tur",
+            Language::Swedish => "swe",
+            Language::Norwegian => "nor",
+            Language::Danish => "dan",
+            Language::Finnish => "fin",
+            Language::Czech => "ces",
+            Language::Hungarian => "hun",
+            Language::Greek => "ell",
+            Language::Hebrew => "heb",
+            Language::Thai => "tha",
+            Language::Vietnamese => "vie",
+            Language::Ukrainian => "ukr",
+            Language::Tamil => "tam",
+            Language::Telugu => "tel",
+            Language::Multi => "mul",
+        }
+    }
+}
+
+/// The shortest ISO 639 code, e.g. `de` or `mul`.
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.iso639_1().unwrap_or_else(|| self.iso639_2()))
+    }
+}
+
+/// Accepts what `Language::from_raw` does: codes as well as English names.
+impl TryFrom<String> for Language {
+    type Error = String;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        Language::from_raw(&raw).ok_or_else(|| format!("unknown language `{}`", raw))
+    }
+}
+
+impl From<Language> for String {
+    fn from(value: Language) -> Self {
+        value.to_string()
+    }
+}
+
+/// A subtitle track, either announced in a release name as in `NLSubs` or
+/// described by a sidecar file name such as `Movie.2019.en.forced.srt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Subtitle {
+    pub language: Option<Language>,
+    /// Only covers foreign dialogue and signs.
+    pub forced: bool,
+    /// Subtitles for the deaf and hard of hearing, also tagged `cc`.
+    pub sdh: bool,
+}
+
+/// The language code followed by the flags, e.g. `en forced SDH`. Unknown
+/// languages are written `und`.
+impl fmt::Display for Subtitle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.language {
+            Some(language) => write!(f, "{}", language)?,
+            None => f.write_str("und")?,
+        }
+        if self.forced {
+            f.write_str(" forced")?;
+        }
+        if self.sdh {
+            f.write_str(" SDH")?;
+        }
+        Ok(())
+    }
+}
+
+/// The component of a path a field of `Metadata::from_path` was read from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Source {
+    /// The file name itself.
+    FileName,
+    /// A folder such as `Season 02`, `S02` or `Specials` right above the file.
+    SeasonFolder,
+    /// The closest folder above the file and its season folder, named after
+    /// the show or the whole release.
+    ShowFolder,
+    /// The name of the torrent holding the file, see `Metadata::from_torrent`.
+    Torrent,
+}
+
+/// Scene release tags such as `PROPER`, `iNTERNAL` or `NFOFIX`, combined with
+/// `|`.
+///```
+/// use torrent_name_parser::{Metadata, ReleaseFlags};
+///
+/// let m = Metadata::from("Movie.2019.REAL.PROPER.1080p.BluRay.x264-GRP").unwrap();
+/// assert!(m.flags().contains(ReleaseFlags::REAL | ReleaseFlags::PROPER));
+/// assert!(!m.flags().is_fix());
+///```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<String>", into = "Vec<String>")
+)]
+pub struct ReleaseFlags(u16);
+
+impl ReleaseFlags {
+    pub const PROPER: Self = ReleaseFlags(1);
+    pub const REPACK: Self = ReleaseFlags(1 << 1);
+    pub const INTERNAL: Self = ReleaseFlags(1 << 2);
+    pub const LIMITED: Self = ReleaseFlags(1 << 3);
+    pub const REAL: Self = ReleaseFlags(1 << 4);
+    pub const RERIP: Self = ReleaseFlags(1 << 5);
+    pub const DUBBED: Self = ReleaseFlags(1 << 6);
+    pub const SUBBED: Self = ReleaseFlags(1 << 7);
+    pub const READNFO: Self = ReleaseFlags(1 << 8);
+    pub const NFOFIX: Self = ReleaseFlags(1 << 9);
+    pub const DIRFIX: Self = ReleaseFlags(1 << 10);
+    pub const SYNCFIX: Self = ReleaseFlags(1 << 11);
+    pub const CONVERT: Self = ReleaseFlags(1 << 12);
+
+    const NAMES: [(Self, &'static str); 13] = [
+        (ReleaseFlags::PROPER, "PROPER"),
+        (ReleaseFlags::REPACK, "REPACK"),
+        (ReleaseFlags::INTERNAL, "INTERNAL"),
+        (ReleaseFlags::LIMITED, "LIMITED"),
+        (ReleaseFlags::REAL, "REAL"),
+        (ReleaseFlags::RERIP, "RERIP"),
+        (ReleaseFlags::DUBBED, "DUBBED"),
+        (ReleaseFlags::SUBBED, "SUBBED"),
+        (ReleaseFlags::READNFO, "READNFO"),
+        (ReleaseFlags::NFOFIX, "NFOFIX"),
+        (ReleaseFlags::DIRFIX, "DIRFIX"),
+        (ReleaseFlags::SYNCFIX, "SYNCFIX"),
+        (ReleaseFlags::CONVERT, "CONVERT"),
+    ];
+
+    pub const fn empty() -> Self {
+        ReleaseFlags(0)
+    }
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Whether every flag of `other` is set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+    /// Whether this release only fixes another one: `NFOFIX`, `DIRFIX` or
+    /// `SYNCFIX`.
+    pub const fn is_fix(self) -> bool {
+        self.0 & (ReleaseFlags::NFOFIX.0 | ReleaseFlags::DIRFIX.0 | ReleaseFlags::SYNCFIX.0) != 0
+    }
+
+    /// Names of the flags set, e.g. `["REAL", "PROPER"]`.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        ReleaseFlags::NAMES
+            .iter()
+            .filter(move |&&(flag, _)| self.contains(flag))
+            .map(|&(_, name)| name)
+    }
+
+    /// The flag named `raw`, ignoring case.
+    pub(crate) fn from_name(raw: &str) -> Option<Self> {
+        ReleaseFlags::NAMES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(raw))
+            .map(|&(flag, _)| flag)
+    }
+}
+
+impl std::ops::BitOr for ReleaseFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        ReleaseFlags(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ReleaseFlags {
+    fn bitor_assign(&mut self, other: Self) {
+        self.insert(other);
+    }
+}
+
+impl fmt::Display for ReleaseFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.names().collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl From<Vec<String>> for ReleaseFlags {
+    fn from(names: Vec<String>) -> Self {
+        names
+            .iter()
+            .filter_map(|name| ReleaseFlags::from_name(name))
+            .fold(ReleaseFlags::empty(), |flags, flag| flags | flag)
+    }
+}
+
+impl From<ReleaseFlags> for Vec<String> {
+    fn from(flags: ReleaseFlags) -> Self {
+        flags.names().map(String::from).collect()
+    }
+}
+
+/// Cut or release edition of a film, as detected by `pattern::EDITION`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Edition {
+    DirectorsCut,
+    Theatrical,
+    FinalCut,
+    Remastered,
+    Criterion,
+    Imax,
+    SpecialEdition,
+    AnniversaryEdition,
+    Uncut,
+    OpenMatte,
+}
+
+impl Edition {
+    pub(crate) fn from_raw(raw: &str) -> Option<Self> {
+        let key = normalize(raw);
+        if key.contains("anniversary") {
+            return Some(Edition::AnniversaryEdition);
+        }
+        match key.as_str() {
+            "directorscut" | "directorcut" | "dc" => Some(Edition::DirectorsCut),
+            "theatrical" | "theatricalcut" | "theatricaledition" => Some(Edition::Theatrical),
+            "finalcut" | "thefinalcut" => Some(Edition::FinalCut),
+            "remaster" | "remastered" => Some(Edition::Remastered),
+            "criterion" | "criterioncollection" => Some(Edition::Criterion),
+            "imax" | "imaxedition" => Some(Edition::Imax),
+            "specialedition" => Some(Edition::SpecialEdition),
+            "uncut" => Some(Edition::Uncut),
+            "openmatte" => Some(Edition::OpenMatte),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Edition::DirectorsCut => "Director's Cut",
+            Edition::Theatrical => "Theatrical",
+            Edition::FinalCut => "Final Cut",
+            Edition::Remastered => "Remastered",
+            Edition::Criterion => "Criterion",
+            Edition::Imax => "IMAX",
+            Edition::SpecialEdition => "Special Edition",
+            Edition::AnniversaryEdition => "Anniversary Edition",
+            Edition::Uncut => "Uncut",
+            Edition::OpenMatte => "Open Matte",
+        })
+    }
+}
+
+/// Service a WEB release was captured from, as detected by
+/// `pattern::STREAMING_SERVICE`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum StreamingService {
+    Amazon,
+    AppleTvPlus,
+    BbcIplayer,
+    Crunchyroll,
+    DisneyPlus,
+    HboMax,
+    Hulu,
+    ITunes,
+    Max,
+    Netflix,
+    ParamountPlus,
+    Peacock,
+}
+
+impl StreamingService {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "AMZN" => Some(StreamingService::Amazon),
+            "ATVP" => Some(StreamingService::AppleTvPlus),
+            "iP" => Some(StreamingService::BbcIplayer),
+            "CR" => Some(StreamingService::Crunchyroll),
+            "DSNP" => Some(StreamingService::DisneyPlus),
+            "HMAX" => Some(StreamingService::HboMax),
+            "HULU" => Some(StreamingService::Hulu),
+            "iT" => Some(StreamingService::ITunes),
+            "MAX" => Some(StreamingService::Max),
+            "NF" => Some(StreamingService::Netflix),
+            "PMTP" => Some(StreamingService::ParamountPlus),
+            "PCOK" => Some(StreamingService::Peacock),
+            _ => None,
+        }
+    }
+
+    /// The scene tag for the service, e.g. `AMZN`.
+    pub fn tag(self) -> &'static str {
+        match self {
+            StreamingService::Amazon => "AMZN",
+            StreamingService::AppleTvPlus => "ATVP",
+            StreamingService::BbcIplayer => "iP",
+            StreamingService::Crunchyroll => "CR",
+            StreamingService::DisneyPlus => "DSNP",
+            StreamingService::HboMax => "HMAX",
+            StreamingService::Hulu => "HULU",
+            StreamingService::ITunes => "iT",
+            StreamingService::Max => "MAX",
+            StreamingService::Netflix => "NF",
+            StreamingService::ParamountPlus => "PMTP",
+            StreamingService::Peacock => "PCOK",
+        }
+    }
+}
+
+impl fmt::Display for StreamingService {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
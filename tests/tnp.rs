@@ -0,0 +1,53 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn tnp(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tnp"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn parses_arguments() {
+    let output = tnp(&["--format", "json", "Show.S01E02.720p"], "");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with(r#"{"name":"Show.S01E02.720p","title":"Show""#));
+}
+
+#[test]
+fn reads_stdin() {
+    let output = tnp(&["-f", "csv"], "Show.S01E02.720p\n\nMovie.2019.1080p\n");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout.starts_with("name,title,"));
+}
+
+#[test]
+fn fails_on_unparsable_names() {
+    let output = tnp(&["Show.S01E02.720p", "S01E01.720p"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stdout).unwrap().contains("Show"));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("tnp: S01E01.720p: "));
+}
+
+#[test]
+fn rejects_bad_usage() {
+    assert_eq!(tnp(&["--format", "xml"], "").status.code(), Some(2));
+    assert_eq!(tnp(&["--bogus"], "").status.code(), Some(2));
+    assert_eq!(tnp(&["--help"], "").status.code(), Some(0));
+}